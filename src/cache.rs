@@ -0,0 +1,88 @@
+use super::filescraper::read_file;
+use super::ExtrinsicResult;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use failure::Error;
+
+/// Keeps parsed benchmark results in memory, so files which did not change
+/// since they were last loaded are not parsed again. Whether a file changed is
+/// determined by its modification time.
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: HashMap<PathBuf, (SystemTime, ExtrinsicResult)>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Cache {
+            entries: HashMap::new(),
+        }
+    }
+    /// Returns the cached result of the file at `path`, or parses the file if
+    /// it was not loaded before or was modified since.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::Cache;
+    /// let mut cache = Cache::new();
+    /// let path = "tests/files/shortened/balances.transfer.txt";
+    ///
+    /// assert!(cache.get_or_load(path.as_ref()).is_ok());
+    /// assert!(cache.get_or_load(path.as_ref()).is_ok());
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn get_or_load(&mut self, path: &Path) -> Result<&ExtrinsicResult, Error> {
+        let modified = fs::metadata(path)?.modified()?;
+
+        let is_stale = match self.entries.get(path) {
+            Some((cached, _)) => *cached != modified,
+            None => true,
+        };
+
+        if is_stale {
+            let result = read_file(path)?.parse()?;
+            self.entries.insert(path.to_path_buf(), (modified, result));
+        }
+
+        Ok(&self.entries[path].1)
+    }
+    /// Amount of cached files.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_or_load_reparses_modified_files() {
+        let path = std::env::temp_dir().join("bench-review-cache-test.txt");
+        fs::copy("tests/files/shortened/balances.transfer.txt", &path).unwrap();
+
+        let mut cache = Cache::new();
+        assert!(cache.is_empty());
+        assert_eq!(cache.get_or_load(&path).unwrap().extrinsic, "transfer");
+
+        // Unmodified files are not read again
+        cache.entries.get_mut(&path).unwrap().1.extrinsic = "cached".to_string();
+        assert_eq!(cache.get_or_load(&path).unwrap().extrinsic, "cached");
+
+        // Files whose modification time differs from the cached one are parsed again
+        fs::copy("tests/files/shortened/staking.bond_extra.txt", &path).unwrap();
+        cache.entries.get_mut(&path).unwrap().0 = SystemTime::UNIX_EPOCH;
+        assert_eq!(cache.get_or_load(&path).unwrap().extrinsic, "bond_extra");
+        assert_eq!(cache.len(), 1);
+
+        fs::remove_file(&path).unwrap();
+        assert!(cache.get_or_load(&path).is_err());
+    }
+}
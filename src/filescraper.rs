@@ -8,6 +8,8 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use failure::Error;
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncRead, AsyncReadExt};
 
 #[derive(Debug, Fail)]
 enum FileContentError {
@@ -21,11 +23,22 @@ pub struct FileContent(pub(crate) (String, PathBuf));
 
 impl FileContent {
     pub fn parse(&self) -> Result<ExtrinsicResult, Error> {
-        let mut extrinsic_result = parser::parse_header(self)
-            .map_err(|_| InvalidDocument((self.0).1.to_string_lossy().to_string()))?;
+        let path = (self.0).1.to_string_lossy().to_string();
+
+        let mut extrinsic_result = parser::parse_header(self).map_err(|err| {
+            debug!("failed to parse header of {}: {}", path, err);
+            InvalidDocument(path.clone())
+        })?;
         let expected_len = extrinsic_result.input_var_names.len() + 2;
-        extrinsic_result.steps_repeats = parser::parse_body(self, expected_len)
-            .map_err(|_| InvalidDocument((self.0).1.to_string_lossy().to_string()))?;
+        extrinsic_result.steps_repeats = parser::parse_body(self, expected_len).map_err(|err| {
+            debug!("failed to parse body of {}: {}", path, err);
+            InvalidDocument(path.clone())
+        })?;
+
+        if extrinsic_result.steps_repeats.is_empty() {
+            warn!("{} does not contain any measurements", path);
+        }
+
         Ok(extrinsic_result)
     }
 }
@@ -67,13 +80,32 @@ fn find_files<P: AsRef<Path>>(path: P) -> Result<Vec<PathBuf>, Error> {
 /// Read file directly to memory. The output of an individual benchmark
 /// output is quite small, so reading the full thing will no create any
 /// issues.
-fn read_file<P: AsRef<Path>>(path: P) -> Result<FileContent, Error> {
+pub(crate) fn read_file<P: AsRef<Path>>(path: P) -> Result<FileContent, Error> {
     let mut file = File::open(path.as_ref())?;
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
     Ok(FileContent((contents, path.as_ref().to_path_buf())))
 }
 
+/// Reads and parses the benchmark result at `path` without blocking the async
+/// runtime. Only reading the file is asynchronous, the parsing itself is not.
+#[cfg(feature = "tokio")]
+pub async fn from_file_async<P: AsRef<Path>>(path: P) -> Result<ExtrinsicResult, Error> {
+    let contents = tokio::fs::read_to_string(path.as_ref()).await?;
+    FileContent((contents, path.as_ref().to_path_buf())).parse()
+}
+
+/// Like `from_file_async`, but reads the benchmark result from any async
+/// stream.
+#[cfg(feature = "tokio")]
+pub async fn from_reader_async<R: AsyncRead + Unpin>(
+    mut reader: R,
+) -> Result<ExtrinsicResult, Error> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents).await?;
+    FileContent((contents, PathBuf::new())).parse()
+}
+
 impl Iterator for FileScraper {
     type Item = Result<FileContent, Error>;
 
@@ -83,3 +115,28 @@ impl Iterator for FileScraper {
         Some(read_file(path.as_path()))
     }
 }
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file_async() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let path = "tests/files/shortened/balances.transfer.txt";
+        let from_file = runtime.block_on(from_file_async(path)).unwrap();
+        let file = tokio::fs::File::from_std(File::open(path).unwrap());
+        let from_reader = runtime.block_on(from_reader_async(file)).unwrap();
+
+        for result in &[from_file, from_reader] {
+            assert_eq!(result.pallet, "balances");
+            assert_eq!(result.extrinsic, "transfer");
+            assert_eq!(result.steps_repeats.len(), 5);
+        }
+
+        assert!(runtime.block_on(from_file_async("README.md")).is_err());
+    }
+}
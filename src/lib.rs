@@ -1,18 +1,40 @@
+// `failure_derive` expands to impls nested inside anonymous consts.
+#![allow(non_local_definitions)]
+
 #[macro_use]
 extern crate failure;
 use failure::Error;
 #[macro_use]
+extern crate log;
+#[macro_use]
 extern crate prettytable;
 
+pub mod cache;
 pub mod filescraper;
 mod parser;
+pub mod prelude;
 pub mod tables;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod weights;
 
+pub use cache::Cache;
 pub use filescraper::{FileContent, FileScraper};
+
+/// Shorter name of `tables::PerExtrTable`, the table of extrinsic time ratios.
+pub type RatioTable<'a> = tables::PerExtrTable<'a>;
+/// Shorter name of `tables::PerExtrTableEntry`.
+pub type RatioEntry<'a> = tables::PerExtrTableEntry<'a>;
+/// Shorter name of `tables::StepIncrTable`.
+pub type StepTable<'a> = tables::StepIncrTable<'a>;
+/// Shorter name of `tables::StepIncrTableEntry`.
+pub type StepEntry<'a> = tables::StepIncrTableEntry<'a>;
 use tables::{PerExtrTable, PerExtrTableEntry, StepIncr, StepIncrTable, StepIncrTableEntry};
 
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Default)]
 pub struct ExtrinsicResult {
@@ -72,14 +94,14 @@ impl ExtrinsicResult {
         self.steps_repeats
             .iter()
             .map(|e| e.extrinsic_time)
-            .fold(0, |acc, num| acc + num)
+            .sum::<u64>()
             .calc_average(self.steps_repeats.len())
     }
     fn average_storage_root_time(&self) -> f64 {
         self.steps_repeats
             .iter()
             .map(|e| e.storage_root_time)
-            .fold(0, |acc, num| acc + num)
+            .sum::<u64>()
             .calc_average(self.steps_repeats.len())
     }
 }
@@ -88,11 +110,34 @@ impl ExtrinsicResult {
 enum ExtrinsicCollectionError {
     #[fail(display = "collection does not contain any results")]
     EmptyResults,
+    #[fail(
+        display = "cannot average the results of {}::{}, they were not measured with the same steps",
+        pallet, extrinsic
+    )]
+    IncompatibleResults { pallet: String, extrinsic: String },
 }
 
 use ExtrinsicCollectionError::*;
 
-#[derive(Debug)]
+/// How `ExtrinsicCollection::merge_with_strategy` handles results of the same
+/// pallet and extrinsic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    KeepFirst,
+    KeepSecond,
+    /// Keeps the result with the higher raw average extrinsic time. No ratios
+    /// are calculated, but within one table the slower result always has the
+    /// higher ratio.
+    KeepHigherRatio,
+    /// Keeps the result with the lower raw average extrinsic time.
+    KeepLowerRatio,
+    /// Combines the measurements of both results, so that every average is the
+    /// arithmetic mean of the averages of both results. Requires both results to
+    /// have the same steps, repeats and input variables.
+    Average,
+}
+
+#[derive(Debug, Default)]
 pub struct ExtrinsicCollection {
     results: Vec<ExtrinsicResult>,
 }
@@ -103,53 +148,213 @@ impl ExtrinsicCollection {
             results: Vec::new(),
         }
     }
+    /// Reads and parses all files directly inside `path`. Subdirectories are
+    /// not searched (see `FileScraper` for a recursive search).
+    ///
+    /// Files which cannot be read or parsed do not abort the scan. A warning is
+    /// logged instead and the file is returned alongside its error.
+    pub fn from_directory<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<(ExtrinsicCollection, Vec<(PathBuf, Error)>), Error> {
+        let mut collection = ExtrinsicCollection::new();
+        let mut failed = Vec::new();
+
+        for entry in fs::read_dir(path)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                continue;
+            }
+
+            match filescraper::read_file(&path).and_then(|content| content.parse()) {
+                Ok(result) => collection.push(result),
+                Err(err) => {
+                    warn!("skipping {}: {}", path.display(), err);
+                    failed.push((path, err));
+                }
+            }
+        }
+
+        debug!(
+            "read {} benchmark results, {} files failed",
+            collection.results.len(),
+            failed.len()
+        );
+
+        Ok((collection, failed))
+    }
     pub fn push(&mut self, result: ExtrinsicResult) {
         self.results.push(result);
     }
-    pub fn generate_ratio_table(&self) -> Result<PerExtrTable, Error> {
+    /// Appends all results of `other` to this collection.
+    pub fn merge(mut self, other: ExtrinsicCollection) -> ExtrinsicCollection {
+        for result in &other.results {
+            if self
+                .results
+                .iter()
+                .any(|r| r.pallet == result.pallet && r.extrinsic == result.extrinsic)
+            {
+                warn!(
+                    "merged collections both contain {}::{}",
+                    result.pallet, result.extrinsic
+                );
+            }
+        }
+
+        debug!(
+            "merging {} benchmark results into {}",
+            other.results.len(),
+            self.results.len()
+        );
+        self.results.extend(other.results);
+        self
+    }
+    /// Appends the results of `other` to this collection, resolving results of
+    /// the same pallet and extrinsic with `strategy` instead of keeping both.
+    ///
+    /// Results can only be averaged if they were measured with the same steps,
+    /// repeats and input variables.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::{ExtrinsicCollection, MergeStrategy};
+    /// # let (full, _) = ExtrinsicCollection::from_directory("tests/files/full/").unwrap();
+    /// # let (shortened, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// // Both directories contain results of the same extrinsics
+    /// let collection = full
+    ///     .merge_with_strategy(shortened, MergeStrategy::KeepHigherRatio)
+    ///     .unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// assert_eq!(table.raw_list().len(), 5);
+    /// assert!(table.find_duplicates().is_empty());
+    /// ```
+    pub fn merge_with_strategy(
+        mut self,
+        other: ExtrinsicCollection,
+        strategy: MergeStrategy,
+    ) -> Result<ExtrinsicCollection, Error> {
+        use MergeStrategy::*;
+
+        for result in other.results {
+            let existing = match self
+                .results
+                .iter_mut()
+                .find(|r| r.pallet == result.pallet && r.extrinsic == result.extrinsic)
+            {
+                Some(existing) => existing,
+                None => {
+                    self.results.push(result);
+                    continue;
+                }
+            };
+
+            debug!(
+                "resolving {}::{} with {:?}",
+                result.pallet, result.extrinsic, strategy
+            );
+
+            match strategy {
+                KeepFirst => {}
+                KeepSecond => *existing = result,
+                KeepHigherRatio => {
+                    if result.average_extrinsic_time() > existing.average_extrinsic_time() {
+                        *existing = result;
+                    }
+                }
+                KeepLowerRatio => {
+                    if result.average_extrinsic_time() < existing.average_extrinsic_time() {
+                        *existing = result;
+                    }
+                }
+                Average => {
+                    // Identical steps give every step and the whole result the
+                    // same amount of measurements on both sides.
+                    let same_steps = existing
+                        .steps_repeats
+                        .iter()
+                        .map(|s| &s.input_vars)
+                        .eq(result.steps_repeats.iter().map(|s| &s.input_vars));
+
+                    if result.input_var_names != existing.input_var_names
+                        || result.steps != existing.steps
+                        || result.repeats != existing.repeats
+                        || !same_steps
+                    {
+                        return Err(IncompatibleResults {
+                            pallet: result.pallet,
+                            extrinsic: result.extrinsic,
+                        }
+                        .into());
+                    }
+
+                    existing.repeats += result.repeats;
+                    existing.steps_repeats.extend(result.steps_repeats);
+                }
+            }
+        }
+
+        Ok(self)
+    }
+    /// Only keeps the results of the given pallet.
+    pub fn filter_by_pallet(mut self, pallet: &str) -> ExtrinsicCollection {
+        self.results.retain(|r| r.pallet == pallet);
+        self
+    }
+    pub fn generate_ratio_table(&self) -> Result<PerExtrTable<'_>, Error> {
         if self.results.is_empty() {
             return Err(EmptyResults.into());
         }
 
-        // find base (lowest value)
+        // find base (lowest value), ignoring results without a usable time
         let base = self
             .results
             .iter()
+            .map(|result| result.average_extrinsic_time())
+            .filter(|time| time.is_finite() && *time > 0.0)
             .min_by(|a, b| {
-                a.average_extrinsic_time()
-                    .partial_cmp(&b.average_extrinsic_time())
+                a.partial_cmp(b)
                     // can occur if there's only one entry
                     .unwrap_or(Ordering::Equal)
             })
-            .ok_or(EmptyResults)?
-            .average_extrinsic_time();
+            .unwrap_or(f64::NAN);
 
         let mut table = PerExtrTable::new();
 
-        self.results.iter().for_each(|result| {
+        for result in &self.results {
             let avg_time = result.average_extrinsic_time();
-            table.push(PerExtrTableEntry {
+            let entry = PerExtrTableEntry {
                 pallet: &result.pallet,
                 extrinsic: &result.extrinsic,
-                avg_extrinsic_time: avg_time.round_by(4),
-                avg_storage_root_time: result.average_storage_root_time().round_by(4),
+                avg_extrinsic_time: avg_time.round_by(4).into(),
+                avg_storage_root_time: result.average_storage_root_time().round_by(4).into(),
                 ratio: (avg_time / base).round_by(4),
                 percentage: ((avg_time / base - 1.0) * 100.0).round_by(4),
-            });
-        });
+            };
+
+            if let Err(err) = table.push(entry) {
+                warn!("skipping {}::{}: {}", result.pallet, result.extrinsic, err);
+            }
+        }
 
         Ok(table)
     }
-    pub fn generate_step_table(&self) -> Result<StepIncrTable, Error> {
+    pub fn generate_step_table(&self) -> Result<StepIncrTable<'_>, Error> {
         if self.results.is_empty() {
             return Err(EmptyResults.into());
         }
 
         // Signature: (pallet, extrinsic) -> ((input vars) -> (count, extrinsic time, storage root time))
+        #[allow(clippy::type_complexity)]
         let mut db: HashMap<(&str, &str), HashMap<&Vec<u64>, (usize, u64, u64)>> = HashMap::new();
+        // Header information of each extrinsic, such as the configured steps and repeats.
+        let mut headers: HashMap<(&str, &str), &ExtrinsicResult> = HashMap::new();
 
         // For each extrinsic result...
         for result in &self.results {
+            headers
+                .entry((&result.pallet, &result.extrinsic))
+                .or_insert(result);
+
             // ... and for each of its steps/repeats...
             for step in &result.steps_repeats {
                 // ... create an entry...
@@ -182,9 +387,16 @@ impl ExtrinsicCollection {
         let mut table = StepIncrTable::new();
         // For each extrinsic ...
         for ((pallet, extrinsic), data) in db {
-            let mut new_entry = StepIncrTableEntry::default();
-            new_entry.pallet = pallet;
-            new_entry.extrinsic = extrinsic;
+            // Every key in `db` was inserted into `headers` too.
+            let header = headers[&(pallet, extrinsic)];
+            let mut new_entry = StepIncrTableEntry {
+                pallet,
+                extrinsic,
+                steps: header.steps,
+                repeats: header.repeats,
+                input_var_names: &header.input_var_names,
+                ..Default::default()
+            };
 
             // ... and for each of its steps...
             for (input_vars, (count, extrinsic_time, storage_root_time)) in data {
@@ -192,53 +404,23 @@ impl ExtrinsicCollection {
                 // get adjusted later on, since all averages have to be calculated
                 // first.
                 new_entry.step_incrs.push(StepIncr {
-                    input_vars: input_vars,
-                    avg_extrinsic_time: extrinsic_time.calc_average(count).round_by(4),
-                    avg_storage_root_time: storage_root_time.calc_average(count).round_by(4),
+                    input_vars,
+                    avg_extrinsic_time: extrinsic_time.calc_average(count).round_by(4).into(),
+                    avg_storage_root_time: storage_root_time.calc_average(count).round_by(4).into(),
                     ratio: 0.0,
                     extrinsic_incr_percentage: 0.0,
                     storage_root_incr_percentage: 0.0,
                 })
             }
 
-            // Get the smallest value of extrinsic time measurement.
-            let extrinsic_base = new_entry
-                .step_incrs
-                .iter()
-                .min_by(|a, b| {
-                    a.avg_extrinsic_time
-                        .partial_cmp(&b.avg_extrinsic_time)
-                        // can occur if there's only one entry
-                        .unwrap_or(Ordering::Equal)
-                })
-                .ok_or(EmptyResults)?
-                .avg_extrinsic_time;
-
-            // Get the smallest value of storage root measurement.
-            let storage_root_base = new_entry
-                .step_incrs
-                .iter()
-                .min_by(|a, b| {
-                    a.avg_storage_root_time
-                        .partial_cmp(&b.avg_storage_root_time)
-                        // can occur if there's only one entry
-                        .unwrap_or(Ordering::Equal)
-                })
-                .ok_or(EmptyResults)?
-                .avg_storage_root_time;
-
-            // Based on the smallest value, calculate the increase of each step in percentages.
-            for entry in &mut new_entry.step_incrs {
-                entry.ratio = (entry.avg_extrinsic_time / extrinsic_base).round_by(4);
-                entry.extrinsic_incr_percentage =
-                    ((entry.avg_extrinsic_time / extrinsic_base - 1.0) * 100.0).round_by(4);
-                entry.storage_root_incr_percentage =
-                    ((entry.avg_storage_root_time / storage_root_base - 1.0) * 100.0).round_by(4);
-            }
+            // Based on the smallest values, calculate the increase of each step in percentages.
+            new_entry.recalculate_percentages();
 
             //percentage: ((avg_time / base - 1.0) * 100.0).round_by(4),
 
-            table.push(new_entry);
+            if let Err(err) = table.try_push(new_entry) {
+                warn!("skipping {}::{}: {}", pallet, extrinsic, err);
+            }
         }
 
         Ok(table)
@@ -1,6 +1,7 @@
-use clap::{App, Arg, SubCommand};
+use clap::{App, Arg, ArgMatches, SubCommand};
 use failure::Error;
 
+use libreview::tables::{PrintOptions, TimeFormatter, TimeUnit};
 use libreview::{ExtrinsicCollection, FileScraper};
 
 fn build_collection(path: &str, skip_warn: bool) -> Result<ExtrinsicCollection, Error> {
@@ -12,19 +13,43 @@ fn build_collection(path: &str, skip_warn: bool) -> Result<ExtrinsicCollection,
             .parse()
             .map(|result| {
                 collection.push(result);
-                ()
             })
             .map_err(|err| {
                 if !skip_warn {
-                    eprintln!("Warn: {}", err.to_string());
+                    eprintln!("Warn: {}", err);
                 }
-                ()
             });
     }
 
     Ok(collection)
 }
 
+fn print_options(matches: &ArgMatches) -> PrintOptions {
+    // Unwrapping is ok, since "unit" has a default value
+    let display_unit = match matches.value_of("unit").unwrap() {
+        "us" => TimeUnit::Microseconds,
+        "ms" => TimeUnit::Milliseconds,
+        "auto" => TimeUnit::Auto,
+        _ => TimeUnit::Nanoseconds,
+    };
+
+    PrintOptions {
+        time_formatter: TimeFormatter {
+            display_unit,
+            ..Default::default()
+        },
+        group_by_pallet: matches.is_present("group-by-pallet"),
+    }
+}
+
+fn unit_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("unit")
+        .long("unit")
+        .takes_value(true)
+        .possible_values(&["ns", "us", "ms", "auto"])
+        .default_value("ns")
+}
+
 fn main() -> Result<(), Error> {
     let matches = App::new("bench-review")
         .version("1.0")
@@ -34,12 +59,15 @@ fn main() -> Result<(), Error> {
             SubCommand::with_name("per-extrinsic")
                 .arg(Arg::with_name("PATH").required(true))
                 .arg(Arg::with_name("csv").long("csv"))
+                .arg(unit_arg())
+                .arg(Arg::with_name("group-by-pallet").long("group-by-pallet"))
                 .arg(Arg::with_name("skip-warnings").long("skip-warnings")),
         )
         .subcommand(
             SubCommand::with_name("per-step")
                 .arg(Arg::with_name("PATH").required(true))
                 .arg(Arg::with_name("csv").long("csv"))
+                .arg(unit_arg())
                 .arg(Arg::with_name("skip-warnings").long("skip-warnings")),
         )
         .get_matches();
@@ -54,10 +82,11 @@ fn main() -> Result<(), Error> {
         let mut table = collection.generate_ratio_table()?;
         table.sort_by_ratio();
 
+        let options = print_options(matches);
         if matches.is_present("csv") {
-            table.print_csv();
+            table.print_csv_with_options(&options);
         } else {
-            table.print();
+            table.print_with_options(&options);
         }
     }
 
@@ -71,10 +100,11 @@ fn main() -> Result<(), Error> {
         let mut table = collection.generate_step_table()?;
         table.sort_by_extrinsic_incr_percentage();
 
+        let options = print_options(matches);
         if matches.is_present("csv") {
-            table.print_csv();
+            table.print_csv_with_options(&options);
         } else {
-            table.print();
+            table.print_with_options(&options);
         }
     }
 
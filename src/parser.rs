@@ -30,7 +30,7 @@ pub(crate) fn parse_header(content: &FileContent) -> Result<ExtrinsicResult, Err
     // Parse the first line
     {
         let parts: Vec<&str> = lines
-            .get(0)
+            .first()
             .ok_or(MissingHeader)?
             .split_whitespace()
             .collect();
@@ -114,7 +114,7 @@ pub(crate) fn parse_body(
         }
 
         // All parts must be numeric
-        if parts.iter().all(|p| !p.parse::<usize>().is_ok()) {
+        if parts.iter().all(|p| p.parse::<usize>().is_err()) {
             break;
         }
 
@@ -176,6 +176,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::explicit_counter_loop, clippy::needless_range_loop)]
 mod tests {
     use super::*;
     use crate::FileContent;
@@ -0,0 +1,30 @@
+//! Re-exports of the commonly used types, so that most uses of the library
+//! only need a single import.
+//!
+//! # Example
+//! ```
+//! use libreview::prelude::*;
+//!
+//! let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+//! let mut table: RatioTable = collection.generate_ratio_table().unwrap();
+//! table.sort_by_ratio_with_order(SortOrder::Descending);
+//!
+//! let entry: &RatioEntry = &table[("identity", "add_registrar")];
+//! assert_eq!(entry.ratio, 1.0);
+//! ```
+
+pub use crate::cache::Cache;
+pub use crate::filescraper::{FileContent, FileScraper};
+pub use crate::tables::{
+    BenchTime, CompositeEntry, CompositeRatioTable, CsvOptions, Extrapolation, Grade,
+    GradingCriteria, HardwareProfile, LineEnding, LowSampleWarning, MonotonicityViolation,
+    OwnedPerExtrTableEntry, PalletExtremes, PerExtrTable, PerExtrTableEntry,
+    PerExtrTableEntryVecExt, PrintOptions, ReportCard, Severity, SortOrder, StepIncr,
+    StepIncrTable, StepIncrTableEntry, StepPalletSummary, Summary, TimeFormatter, TimeUnit,
+    ValidationError, WindowStats,
+};
+pub use crate::weights::{LinearFit, WeightTestResult};
+pub use crate::{
+    ExtrinsicCollection, ExtrinsicResult, MergeStrategy, RatioEntry, RatioTable, StepEntry,
+    StepTable,
+};
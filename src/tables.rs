@@ -1,139 +1,4301 @@
+//! Overview tables of the benchmark results.
+//!
+//! # Sorting
+//!
+//! All sorting methods of the tables are stable: entries which compare as equal
+//! keep their relative order from before the sort. Sorting the same input
+//! therefore always produces the same output. Entries with NaN or infinite
+//! values are always placed last.
+//!
+//! ## `PerExtrTable::sort_by_ratio`
+//! Sorts by the ratio of each extrinsic compared to the fastest one. Answers
+//! "which extrinsics are the most expensive relative to the others?". Since the
+//! ratio is derived from the average extrinsic time, this is the same order as
+//! `sort_by_avg_extrinsic_time`. Use `sort_by_ratio_desc` to list the most
+//! expensive extrinsics first.
+//!
+//! ```
+//! # use libreview::ExtrinsicCollection;
+//! let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+//! let mut table = collection.generate_ratio_table().unwrap();
+//!
+//! table.sort_by_ratio_desc();
+//! assert_eq!(table.raw_list()[0].1, "delegate");
+//! ```
+//!
+//! ## `PerExtrTable::sort_by_avg_extrinsic_time`
+//! Sorts by the absolute average extrinsic time. Answers "how long does the
+//! execution of each extrinsic take?", e.g. when comparing against the block
+//! time.
+//!
+//! ```
+//! # use libreview::ExtrinsicCollection;
+//! # use libreview::tables::SortOrder;
+//! let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+//! let mut table = collection.generate_ratio_table().unwrap();
+//!
+//! table.sort_by_avg_extrinsic_time_with_order(SortOrder::Descending);
+//! assert_eq!(table.raw_list()[0].2, 1501419.6);
+//! ```
+//!
+//! ## `PerExtrTable::sort_by_storage_root_time`
+//! Sorts by the average storage root time. Answers "which extrinsics stress the
+//! storage layer the most?", independent of the cost of the computation itself.
+//!
+//! ```
+//! # use libreview::ExtrinsicCollection;
+//! let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+//! let mut table = collection.generate_ratio_table().unwrap();
+//!
+//! table.sort_by_storage_root_time();
+//! assert_eq!(table.raw_list()[0].1, "add_registrar");
+//! ```
+//!
+//! ## `StepIncrTable::sort_by_extrinsic_incr_percentage`
+//! Sorts the steps of each extrinsic by how much slower they are than the
+//! fastest step of that same extrinsic, highest first. Answers "how much do the
+//! input variables influence the execution time?", which is what the weight
+//! functions have to account for.
+//!
+//! ```
+//! # use libreview::ExtrinsicCollection;
+//! let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+//! let mut table = collection.generate_step_table().unwrap();
+//!
+//! table.sort_by_extrinsic_incr_percentage();
+//! assert_eq!(table.raw_list()[0].6, 15.6470);
+//! ```
+
+use super::RoundBy;
+
 use std::cmp::Ordering;
-use std::io::stdout;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io::{stdout, Write};
+use std::ops::{Index, IndexMut};
+use std::path::{Path, PathBuf};
 
-#[derive(Debug)]
+use failure::Error;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Time unit of the raw measurements of the Substrate benchmark output.
+pub type NanoSeconds = f64;
+
+/// A measured (or averaged) benchmark time. Carries the unit explicitly, so
+/// callers have to choose the unit when converting to a plain number.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BenchTime(NanoSeconds);
+
+impl BenchTime {
+    /// # Example
+    /// ```
+    /// # use libreview::tables::BenchTime;
+    /// let time = BenchTime::from(1_500_000.0);
+    /// assert_eq!(time.to_ns(), 1_500_000.0);
+    /// ```
+    pub fn to_ns(&self) -> f64 {
+        self.0
+    }
+    /// # Example
+    /// ```
+    /// # use libreview::tables::BenchTime;
+    /// let time = BenchTime::from(1_500_000.0);
+    /// assert_eq!(time.to_us(), 1_500.0);
+    /// ```
+    pub fn to_us(&self) -> f64 {
+        self.0 / 1_000.0
+    }
+    /// # Example
+    /// ```
+    /// # use libreview::tables::BenchTime;
+    /// let time = BenchTime::from(1_500_000.0);
+    /// assert_eq!(time.to_ms(), 1.5);
+    /// ```
+    pub fn to_ms(&self) -> f64 {
+        self.0 / 1_000_000.0
+    }
+}
+
+/// Interprets the value as nanoseconds.
+impl From<f64> for BenchTime {
+    fn from(ns: f64) -> Self {
+        BenchTime(ns)
+    }
+}
+
+/// Returns the value in nanoseconds.
+impl From<BenchTime> for f64 {
+    fn from(time: BenchTime) -> Self {
+        time.0
+    }
+}
+
+/// Logs a warning if any of the values which are about to be sorted is not
+/// finite, since those get moved to the end.
+fn warn_non_finite(field: &str, values: impl Iterator<Item = f64>) {
+    let count = values.filter(|v| !v.is_finite()).count();
+    if count > 0 {
+        warn!(
+            "sorting {} non-finite value(s) of `{}`, moving them to the end",
+            count, field
+        );
+    }
+}
+
+/// Subtracts `overhead_ns` from `time`, clamping at zero.
+fn discount(time: BenchTime, overhead_ns: f64) -> BenchTime {
+    (time.to_ns() - overhead_ns).max(0.0).round_by(4).into()
+}
+
+/// The lowest finite, positive time, which ratios and percentages are relative
+/// to. NaN if there is none, which makes all ratios NaN.
+fn ratio_base(times: impl Iterator<Item = f64>) -> f64 {
+    times
+        .filter(|time| time.is_finite() && *time > 0.0)
+        .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+        .unwrap_or(f64::NAN)
+}
+
+/// The unit in which times get displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    /// Picks the largest unit for which all (non-zero) times of the table are
+    /// at least 1.0.
+    Auto,
+}
+
+impl TimeUnit {
+    fn label(self) -> &'static str {
+        match self {
+            TimeUnit::Nanoseconds => "ns",
+            TimeUnit::Microseconds => "µs",
+            TimeUnit::Milliseconds => "ms",
+            TimeUnit::Auto => "",
+        }
+    }
+    fn convert(self, time: BenchTime) -> f64 {
+        match self {
+            TimeUnit::Nanoseconds | TimeUnit::Auto => time.to_ns(),
+            TimeUnit::Microseconds => time.to_us(),
+            TimeUnit::Milliseconds => time.to_ms(),
+        }
+    }
+}
+
+/// Controls how times are displayed when printing tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeFormatter {
+    pub display_unit: TimeUnit,
+    pub decimal_places: usize,
+}
+
+impl Default for TimeFormatter {
+    fn default() -> Self {
+        TimeFormatter {
+            display_unit: TimeUnit::Nanoseconds,
+            decimal_places: 4,
+        }
+    }
+}
+
+impl TimeFormatter {
+    /// Returns the unit that is used for displaying `times`. Only differs
+    /// from `display_unit` if it is set to `TimeUnit::Auto`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{BenchTime, TimeFormatter, TimeUnit};
+    /// let formatter = TimeFormatter {
+    ///     display_unit: TimeUnit::Auto,
+    ///     decimal_places: 2,
+    /// };
+    ///
+    /// let times = [BenchTime::from(76_600.8), BenchTime::from(1_501_419.6)];
+    /// assert_eq!(formatter.resolve_unit(&times), TimeUnit::Microseconds);
+    /// ```
+    pub fn resolve_unit(&self, times: &[BenchTime]) -> TimeUnit {
+        if self.display_unit != TimeUnit::Auto {
+            return self.display_unit;
+        }
+
+        [TimeUnit::Milliseconds, TimeUnit::Microseconds]
+            .iter()
+            .copied()
+            .find(|unit| {
+                times
+                    .iter()
+                    .map(|time| unit.convert(*time))
+                    .filter(|value| *value != 0.0)
+                    .all(|value| value >= 1.0)
+            })
+            .unwrap_or(TimeUnit::Nanoseconds)
+    }
+    /// Formats `time` in the given unit with the configured amount of
+    /// decimal places.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{BenchTime, TimeFormatter, TimeUnit};
+    /// let formatter = TimeFormatter::default();
+    /// let time = BenchTime::from(76_600.8);
+    ///
+    /// assert_eq!(formatter.format(time, TimeUnit::Microseconds), "76.6008");
+    /// ```
+    pub fn format(&self, time: BenchTime, unit: TimeUnit) -> String {
+        format!("{:.*}", self.decimal_places, unit.convert(time))
+    }
+}
+
+/// Options for printing tables.
+#[derive(Debug, Clone, Default)]
+pub struct PrintOptions {
+    pub time_formatter: TimeFormatter,
+    /// Groups the extrinsics of the overview table by pallet, with a pallet
+    /// header before and an average row after each group. Has no effect on
+    /// the step table.
+    pub group_by_pallet: bool,
+}
+
+/// The line terminator of CSV records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    Unix,
+    /// `\r\n`, as expected by Excel.
+    Windows,
+}
+
+/// The CSV dialect used when writing tables as CSV. The delimiter and quote
+/// characters must be ASCII.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub quote_char: char,
+    /// Quote all fields, not only the ones containing special characters.
+    pub always_quote: bool,
+    pub line_ending: LineEnding,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            quote_char: '"',
+            always_quote: false,
+            line_ending: LineEnding::Unix,
+        }
+    }
+}
+
+/// Writes a prettytable as CSV in the dialect of `options`.
+fn write_table_csv<W: Write>(
+    table: &prettytable::Table,
+    writer: W,
+    options: &CsvOptions,
+) -> Result<(), Error> {
+    fn ascii(field: &'static str, value: char) -> Result<u8, TableError> {
+        if value.is_ascii() {
+            Ok(value as u8)
+        } else {
+            Err(TableError::NonAsciiCsvChar { field, value })
+        }
+    }
+
+    // Pallet headers of grouped tables consist of a single cell.
+    let writer = csv::WriterBuilder::new()
+        .flexible(true)
+        .delimiter(ascii("delimiter", options.delimiter)?)
+        .quote(ascii("quote_char", options.quote_char)?)
+        .quote_style(if options.always_quote {
+            csv::QuoteStyle::Always
+        } else {
+            csv::QuoteStyle::Necessary
+        })
+        .terminator(match options.line_ending {
+            LineEnding::Unix => csv::Terminator::Any(b'\n'),
+            LineEnding::Windows => csv::Terminator::CRLF,
+        })
+        .from_writer(writer);
+
+    table.to_csv_writer(writer)?.flush()?;
+    Ok(())
+}
+
+/// The direction in which the table entries get sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    }
+    /// Compares two values in this order. NaN and infinite values are always
+    /// placed after all finite values, independent of the order.
+    fn compare(self, a: f64, b: f64) -> Ordering {
+        match (a.is_finite(), b.is_finite()) {
+            (true, true) => self.apply(a.partial_cmp(&b).unwrap_or(Ordering::Equal)),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => Ordering::Equal,
+        }
+    }
+}
+
+#[derive(Debug, Fail)]
+pub enum ValidationError {
+    #[fail(display = "field `{}` must be a finite number, found {}", field, value)]
+    NonFinite { field: &'static str, value: f64 },
+    #[fail(display = "field `{}` must not be negative, found {}", field, value)]
+    Negative { field: &'static str, value: f64 },
+    #[fail(display = "field `{}` must be positive, found {}", field, value)]
+    NonPositive { field: &'static str, value: f64 },
+    #[fail(display = "field `{}` must not be empty", field)]
+    Empty { field: &'static str },
+    #[fail(
+        display = "all steps must have {} input variables, found {}",
+        expected, found
+    )]
+    InconsistentComponents { expected: usize, found: usize },
+    #[fail(display = "expected {} steps, found {}", expected, found)]
+    MissingSteps { expected: usize, found: usize },
+}
+
+use ValidationError::*;
+
+#[derive(Debug, Fail)]
+enum TableError {
+    #[fail(
+        display = "no extrinsic has the requested amount of components: {}",
+        _0
+    )]
+    NoMatchingComponents(usize),
+    #[fail(
+        display = "CSV option `{}` must be an ASCII character, found {}",
+        field, value
+    )]
+    NonAsciiCsvChar { field: &'static str, value: char },
+}
+
+/// Describes the machine on which benchmarks were executed. Used to normalize
+/// results from different machines to a common reference machine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HardwareProfile {
+    pub cpu_ghz: f64,
+    pub memory_bandwidth_gbps: f64,
+}
+
+impl HardwareProfile {
+    /// Checks that all fields are finite, positive numbers.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::HardwareProfile;
+    /// let mut profile = HardwareProfile {
+    ///     cpu_ghz: 3.0,
+    ///     memory_bandwidth_gbps: 20.0,
+    /// };
+    /// assert!(profile.validate().is_ok());
+    ///
+    /// profile.cpu_ghz = 0.0;
+    /// assert!(profile.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        positive("cpu_ghz", self.cpu_ghz)?;
+        positive("memory_bandwidth_gbps", self.memory_bandwidth_gbps)
+    }
+}
+
+fn positive(field: &'static str, value: f64) -> Result<(), ValidationError> {
+    if !value.is_finite() {
+        return Err(NonFinite { field, value });
+    }
+    if value <= 0.0 {
+        return Err(NonPositive { field, value });
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PerExtrTable<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     entries: Vec<PerExtrTableEntry<'a>>,
 }
 
-#[derive(Debug)]
-pub(crate) struct PerExtrTableEntry<'a> {
-    pub pallet: &'a str,
-    pub extrinsic: &'a str,
-    pub avg_extrinsic_time: f64,
-    pub avg_storage_root_time: f64,
-    pub ratio: f64,
-    pub percentage: f64,
-}
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerExtrTableEntry<'a> {
+    pub pallet: &'a str,
+    pub extrinsic: &'a str,
+    pub avg_extrinsic_time: BenchTime,
+    pub avg_storage_root_time: BenchTime,
+    pub ratio: f64,
+    pub percentage: f64,
+}
+
+impl<'a> PerExtrTableEntry<'a> {
+    /// The sum of the average extrinsic time and the average storage root time,
+    /// i.e. the absolute cost of the extrinsic.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::PerExtrTableEntry;
+    /// let entry = PerExtrTableEntry {
+    ///     pallet: "balances",
+    ///     extrinsic: "transfer",
+    ///     avg_extrinsic_time: 187680.2.into(),
+    ///     avg_storage_root_time: 67901.0.into(),
+    ///     ratio: 2.45,
+    ///     percentage: 145.0,
+    /// };
+    ///
+    /// assert_eq!(entry.total_time().to_ns(), 255581.2);
+    /// ```
+    pub fn total_time(&self) -> BenchTime {
+        (self.avg_extrinsic_time.to_ns() + self.avg_storage_root_time.to_ns())
+            .round_by(4)
+            .into()
+    }
+    /// Classifies the entry by its extrinsic time divided by its storage root
+    /// time (unlike `ratio`, which compares with the fastest extrinsic).
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTableEntry, Severity};
+    /// # let entry = |avg_extrinsic_time: f64| PerExtrTableEntry {
+    /// #     pallet: "balances",
+    /// #     extrinsic: "transfer",
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     percentage: 0.0,
+    /// # };
+    /// assert_eq!(entry(40.0).severity(), Severity::StorageDominated);
+    /// assert_eq!(entry(150.0).severity(), Severity::Balanced);
+    /// assert_eq!(entry(250.0).severity(), Severity::ComputeDominated);
+    ///
+    /// let entries = [entry(40.0), entry(450.0), entry(150.0)];
+    /// let worst = entries.iter().max_by_key(|e| e.severity()).unwrap();
+    /// assert_eq!(worst.avg_extrinsic_time.to_ns(), 450.0);
+    /// ```
+    pub fn severity(&self) -> Severity {
+        let quotient = self.avg_extrinsic_time.to_ns() / self.avg_storage_root_time.to_ns();
+
+        if quotient.is_nan() {
+            Severity::Balanced
+        } else if quotient < 0.5 {
+            Severity::StorageDominated
+        } else if quotient <= 1.5 {
+            Severity::Balanced
+        } else if quotient <= 3.0 {
+            Severity::ComputeDominated
+        } else {
+            Severity::SeverelyComputeDominated
+        }
+    }
+    /// Describes the `severity` of the entry for reviewers.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::PerExtrTableEntry;
+    /// let entry = PerExtrTableEntry {
+    ///     pallet: "balances",
+    ///     extrinsic: "transfer",
+    ///     avg_extrinsic_time: 187680.2.into(),
+    ///     avg_storage_root_time: 67901.0.into(),
+    ///     ratio: 2.45,
+    ///     percentage: 145.0,
+    /// };
+    ///
+    /// assert_eq!(entry.explain_ratio(), "Compute-dominated");
+    /// ```
+    pub fn explain_ratio(&self) -> &'static str {
+        self.severity().description()
+    }
+    /// Describes the ratio of the entry in a sentence, for report prose. The
+    /// ratio is relative to the fastest extrinsic of the table.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::PerExtrTableEntry;
+    /// let entry = PerExtrTableEntry {
+    ///     pallet: "balances",
+    ///     extrinsic: "transfer",
+    ///     avg_extrinsic_time: 187680.2.into(),
+    ///     avg_storage_root_time: 67901.0.into(),
+    ///     ratio: 2.45,
+    ///     percentage: 145.0,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     entry.describe(),
+    ///     "The `transfer` extrinsic in the `balances` pallet takes 2.45x as long \
+    ///      as the fastest extrinsic, an increase of 145.0%."
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        format!(
+            "The `{}` extrinsic in the `{}` pallet takes {}x as long as the fastest extrinsic, \
+             an increase of {:.1}%.",
+            self.extrinsic, self.pallet, self.ratio, self.percentage
+        )
+    }
+}
+
+/// Classification of the extrinsic time relative to the storage root time, see
+/// `PerExtrTableEntry::severity`. Ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The extrinsic time is below half the storage root time.
+    StorageDominated,
+    /// The extrinsic time is between 0.5 and 1.5 times the storage root time.
+    Balanced,
+    /// The extrinsic time is between 1.5 and 3 times the storage root time.
+    ComputeDominated,
+    /// The extrinsic time exceeds 3 times the storage root time.
+    SeverelyComputeDominated,
+}
+
+impl Severity {
+    /// A description for reviewers, e.g. "Compute-dominated".
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::Severity;
+    /// assert_eq!(Severity::StorageDominated.description(), "Storage-dominated");
+    /// ```
+    pub fn description(&self) -> &'static str {
+        match self {
+            Severity::StorageDominated => "Storage-dominated",
+            Severity::Balanced => "Balanced",
+            Severity::ComputeDominated => "Compute-dominated",
+            Severity::SeverelyComputeDominated => "Severely compute-dominated",
+        }
+    }
+}
+
+/// The fastest and slowest extrinsic of a pallet, by ratio.
+#[derive(Debug)]
+pub struct PalletExtremes<'a> {
+    pub pallet: &'a str,
+    /// The entry with the lowest ratio.
+    pub best: &'a PerExtrTableEntry<'a>,
+    /// The entry with the highest ratio.
+    pub worst: &'a PerExtrTableEntry<'a>,
+    /// `worst.ratio - best.ratio`
+    pub spread: f64,
+}
+
+/// Iterator over the pages of a `PerExtrTable`, see `PerExtrTable::page_iter`.
+#[derive(Debug)]
+pub struct PageIter<'a, 'b> {
+    chunks: std::slice::Chunks<'b, PerExtrTableEntry<'a>>,
+    page_number: usize,
+    total_pages: usize,
+}
+
+/// A page of consecutive entries of a `PerExtrTable`.
+#[derive(Debug)]
+pub struct PerExtrTablePage<'a, 'b> {
+    pub entries: &'b [PerExtrTableEntry<'a>],
+    /// Number of this page, starting at 1.
+    pub page_number: usize,
+    pub total_pages: usize,
+}
+
+impl<'a, 'b> Iterator for PageIter<'a, 'b> {
+    type Item = PerExtrTablePage<'a, 'b>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entries = self.chunks.next()?;
+        self.page_number += 1;
+
+        Some(PerExtrTablePage {
+            entries,
+            page_number: self.page_number,
+            total_pages: self.total_pages,
+        })
+    }
+}
+
+impl<'a, 'b> PerExtrTablePage<'a, 'b> {
+    /// Prints the entries of this page, followed by the page number.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::PrintOptions;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// for page in table.page_iter(2) {
+    ///     page.print(&PrintOptions::default());
+    /// }
+    /// ```
+    pub fn print(&self, options: &PrintOptions) {
+        PerExtrTable::build_table_of(self.entries, options).printstd();
+        println!("Page {}/{}", self.page_number, self.total_pages);
+    }
+}
+
+/// Statistics of the ratios of consecutive entries of a `PerExtrTable`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowStats<'a> {
+    /// Index of the first entry in the window.
+    pub start_index: usize,
+    /// Index of the last entry in the window (inclusive).
+    pub end_index: usize,
+    pub window_mean_ratio: f64,
+    pub window_std_dev: f64,
+    pub first_entry_pallet: &'a str,
+    pub last_entry_pallet: &'a str,
+}
+
+/// Letter grade of a `ReportCard`, from best (`A`) to worst (`F`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+impl fmt::Display for Grade {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The rubric of `PerExtrTable::report_card_with_criteria`. Entries with a ratio
+/// above `max_ratio` are violations; the share of violations determines the grade.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradingCriteria {
+    pub max_ratio: f64,
+    pub max_violation_share_a: f64,
+    pub max_violation_share_b: f64,
+    pub max_violation_share_c: f64,
+    pub max_violation_share_d: f64,
+}
+
+impl Default for GradingCriteria {
+    fn default() -> Self {
+        GradingCriteria {
+            max_ratio: 10.0,
+            max_violation_share_a: 0.0,
+            max_violation_share_b: 0.05,
+            max_violation_share_c: 0.1,
+            max_violation_share_d: 0.25,
+        }
+    }
+}
+
+/// A qualitative assessment of a `PerExtrTable`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportCard {
+    pub grade: Grade,
+    pub violations_count: usize,
+    /// Amount of graded entries.
+    pub total_count: usize,
+    pub mean_ratio: f64,
+    /// One-line summary, e.g. `Grade B: 2/47 violations, mean ratio 1.84`.
+    pub summary_text: String,
+}
+
+impl fmt::Display for ReportCard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.summary_text)
+    }
+}
+
+/// Escapes the characters with a special meaning in HTML text.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Statistics of the ratio distribution of a `PerExtrTable`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    /// Amount of entries the statistics are based on.
+    pub count: usize,
+    pub mean_ratio: f64,
+    pub std_dev_ratio: f64,
+    pub min_ratio: f64,
+    pub max_ratio: f64,
+    pub median_ratio: f64,
+    /// 90th percentile
+    pub p90_ratio: f64,
+    /// 99th percentile
+    pub p99_ratio: f64,
+    /// `max_ratio - min_ratio`
+    pub max_pairwise_ratio_difference: f64,
+    /// `max_ratio / median_ratio`
+    pub range_to_median_ratio: f64,
+    /// Share (0.0 to 1.0) of the entries with a ratio above the threshold, see
+    /// `PerExtrTable::summary_with_threshold`.
+    pub fraction_above_threshold: f64,
+}
+
+/// Ratio above which `PerExtrTable::summary` counts an entry towards
+/// `Summary::fraction_above_threshold`.
+pub const DEFAULT_RATIO_THRESHOLD: f64 = 2.0;
+
+impl Summary {
+    /// Calculates the statistics of the `(ratio, weight)` pairs. Pairs with a
+    /// non-finite ratio or a non-positive weight are ignored.
+    fn from_weighted(
+        values: impl Iterator<Item = (f64, f64)>,
+        ratio_threshold: f64,
+    ) -> Option<Summary> {
+        let mut values: Vec<(f64, f64)> = values
+            .filter(|(ratio, weight)| ratio.is_finite() && *weight > 0.0)
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+
+        values.sort_by(|a, b| SortOrder::Ascending.compare(a.0, b.0));
+
+        let total_weight: f64 = values.iter().map(|(_, w)| w).sum();
+        let mean = values.iter().map(|(r, w)| r * w).sum::<f64>() / total_weight;
+        let variance = values
+            .iter()
+            .map(|(r, w)| w * (r - mean).powi(2))
+            .sum::<f64>()
+            / total_weight;
+        let weight_above_threshold: f64 = values
+            .iter()
+            .filter(|(r, _)| *r > ratio_threshold)
+            .map(|(_, w)| w)
+            .sum();
+
+        let (min, max) = (values[0].0, values[values.len() - 1].0);
+        let median = weighted_percentile(&values, 50.0).round_by(4);
+
+        Some(Summary {
+            count: values.len(),
+            mean_ratio: mean.round_by(4),
+            std_dev_ratio: variance.sqrt().round_by(4),
+            min_ratio: min,
+            max_ratio: max,
+            median_ratio: median,
+            p90_ratio: weighted_percentile(&values, 90.0).round_by(4),
+            p99_ratio: weighted_percentile(&values, 99.0).round_by(4),
+            max_pairwise_ratio_difference: (max - min).round_by(4),
+            range_to_median_ratio: (max / median).round_by(4),
+            fraction_above_threshold: (weight_above_threshold / total_weight).round_by(4),
+        })
+    }
+    /// The name and the value of each statistic, in display order.
+    fn fields(&self) -> [(&'static str, String); 11] {
+        [
+            ("Count", self.count.to_string()),
+            ("Mean ratio", self.mean_ratio.to_string()),
+            ("Median ratio", self.median_ratio.to_string()),
+            ("P90 ratio", self.p90_ratio.to_string()),
+            ("P99 ratio", self.p99_ratio.to_string()),
+            ("Std dev ratio", self.std_dev_ratio.to_string()),
+            ("Min ratio", self.min_ratio.to_string()),
+            ("Max ratio", self.max_ratio.to_string()),
+            (
+                "Max pairwise ratio difference",
+                self.max_pairwise_ratio_difference.to_string(),
+            ),
+            (
+                "Range to median ratio",
+                self.range_to_median_ratio.to_string(),
+            ),
+            (
+                "Fraction above threshold",
+                self.fraction_above_threshold.to_string(),
+            ),
+        ]
+    }
+    /// Prints the statistics to stdout in a bordered table, one statistic per
+    /// row, with `label` (e.g. "Balances Pallet") as its title.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// table.summary().unwrap().print("All Pallets");
+    /// ```
+    pub fn print(&self, label: &str) {
+        let mut table = prettytable::Table::new();
+
+        // Header
+        table.add_row(row![bcH2 => label]);
+
+        // Body
+        for (name, value) in self.fields().iter() {
+            table.add_row(row![name, r->value]);
+        }
+
+        table.printstd();
+    }
+}
+
+impl fmt::Display for Summary {
+    /// Writes one `name: value` line per statistic.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// let text = table.summary().unwrap().to_string();
+    ///
+    /// assert!(text.starts_with("Count: 5\nMean ratio: "));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (counter, (name, value)) in self.fields().iter().enumerate() {
+            if counter != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Pearson correlation coefficient of the `(x, y)` pairs. Returns `None` if
+/// either variable does not vary.
+fn pearson_correlation(points: &[(f64, f64)]) -> Option<f64> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+    let covariance: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let variance_x: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let variance_y: f64 = points.iter().map(|(_, y)| (y - mean_y).powi(2)).sum();
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+
+    Some(covariance / (variance_x * variance_y).sqrt())
+}
+
+/// Linearly interpolated `percentile` (0-100) of the `(value, weight)` pairs,
+/// which must be sorted by value.
+fn weighted_percentile(values: &[(f64, f64)], percentile: f64) -> f64 {
+    let total_weight: f64 = values.iter().map(|(_, w)| w).sum();
+    let target = percentile / 100.0 * total_weight;
+
+    let mut cumulative = 0.0;
+    let mut prev: Option<(f64, f64)> = None;
+    for &(value, weight) in values {
+        let position = cumulative + weight / 2.0;
+        if target <= position {
+            return match prev {
+                Some((prev_value, prev_position)) => {
+                    let fraction = (target - prev_position) / (position - prev_position);
+                    prev_value + fraction * (value - prev_value)
+                }
+                None => value,
+            };
+        }
+
+        cumulative += weight;
+        prev = Some((value, position));
+    }
+
+    values[values.len() - 1].0
+}
+
+/// Like `PerExtrTableEntry`, but owns the pallet and extrinsic names, so that
+/// it can outlive the table (and the benchmark results) it was created from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedPerExtrTableEntry {
+    pub pallet: String,
+    pub extrinsic: String,
+    pub avg_extrinsic_time: BenchTime,
+    pub avg_storage_root_time: BenchTime,
+    pub ratio: f64,
+    pub percentage: f64,
+}
+
+impl<'a> From<&PerExtrTableEntry<'a>> for OwnedPerExtrTableEntry {
+    fn from(entry: &PerExtrTableEntry<'a>) -> Self {
+        OwnedPerExtrTableEntry {
+            pallet: entry.pallet.to_string(),
+            extrinsic: entry.extrinsic.to_string(),
+            avg_extrinsic_time: entry.avg_extrinsic_time,
+            avg_storage_root_time: entry.avg_storage_root_time,
+            ratio: entry.ratio,
+            percentage: entry.percentage,
+        }
+    }
+}
+
+/// Convenience methods for collections of detached entries, for when a full
+/// `PerExtrTable` is not needed.
+pub trait PerExtrTableEntryVecExt {
+    /// Average ratio of all entries. NaN if there are no entries.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::PerExtrTableEntryVecExt;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let owned = collection.generate_ratio_table().unwrap().to_owned_entries();
+    /// assert_eq!(owned.mean_ratio().round(), 5.0);
+    /// ```
+    fn mean_ratio(&self) -> f64;
+    /// Sorts the entries by ratio, lowest first. The sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::PerExtrTableEntryVecExt;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut owned = collection.generate_ratio_table().unwrap().to_owned_entries();
+    /// owned.sort_by_ratio();
+    /// assert_eq!(owned[0].extrinsic, "add_registrar");
+    /// ```
+    fn sort_by_ratio(&mut self);
+    /// Returns the entries of the given pallet.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::PerExtrTableEntryVecExt;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let owned = collection.generate_ratio_table().unwrap().to_owned_entries();
+    /// assert_eq!(owned.filter_by_pallet("balances").len(), 1);
+    /// ```
+    fn filter_by_pallet(&self, pallet: &str) -> Vec<&OwnedPerExtrTableEntry>;
+}
+
+impl PerExtrTableEntryVecExt for Vec<OwnedPerExtrTableEntry> {
+    fn mean_ratio(&self) -> f64 {
+        self.iter().map(|e| e.ratio).sum::<f64>() / self.len() as f64
+    }
+    fn sort_by_ratio(&mut self) {
+        self.sort_by(|a, b| SortOrder::Ascending.compare(a.ratio, b.ratio));
+    }
+    fn filter_by_pallet(&self, pallet: &str) -> Vec<&OwnedPerExtrTableEntry> {
+        self.iter().filter(|e| e.pallet == pallet).collect()
+    }
+}
+
+impl<'a> PerExtrTable<'a> {
+    /// # Example
+    /// ```
+    /// # use libreview::tables::PerExtrTable;
+    /// let table = PerExtrTable::new();
+    /// assert!(table.raw_list().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        PerExtrTable {
+            entries: Vec::new(),
+        }
+    }
+    /// Checks whether the entry can be inserted into the table. Requirements:
+    /// - pallet and extrinsic names are not empty
+    /// - times are finite and not negative
+    /// - ratio and percentage are finite, otherwise sorting would not be meaningful
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTable, PerExtrTableEntry};
+    /// let mut entry = PerExtrTableEntry {
+    ///     pallet: "balances",
+    ///     extrinsic: "transfer",
+    ///     avg_extrinsic_time: 187680.2.into(),
+    ///     avg_storage_root_time: 83780.8.into(),
+    ///     ratio: 2.4501,
+    ///     percentage: 145.0108,
+    /// };
+    /// assert!(PerExtrTable::validate_entry(&entry).is_ok());
+    ///
+    /// entry.ratio = f64::NAN;
+    /// assert!(PerExtrTable::validate_entry(&entry).is_err());
+    /// ```
+    pub fn validate_entry(entry: &PerExtrTableEntry) -> Result<(), ValidationError> {
+        let names = [("pallet", entry.pallet), ("extrinsic", entry.extrinsic)];
+        for (field, value) in &names {
+            if value.is_empty() {
+                return Err(Empty { field });
+            }
+        }
+
+        let times = [
+            ("avg_extrinsic_time", entry.avg_extrinsic_time.to_ns()),
+            ("avg_storage_root_time", entry.avg_storage_root_time.to_ns()),
+        ];
+        for &(field, value) in &times {
+            if !value.is_finite() {
+                return Err(NonFinite { field, value });
+            }
+            if value < 0.0 {
+                return Err(Negative { field, value });
+            }
+        }
+
+        let numbers = [("ratio", entry.ratio), ("percentage", entry.percentage)];
+        for &(field, value) in &numbers {
+            if !value.is_finite() {
+                return Err(NonFinite { field, value });
+            }
+        }
+
+        Ok(())
+    }
+    /// Inserts the entry at the end of the table. Invalid entries (see
+    /// `validate_entry`) are rejected.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTable, PerExtrTableEntry};
+    /// # let entry = |ratio| PerExtrTableEntry {
+    /// #     pallet: "balances",
+    /// #     extrinsic: "transfer",
+    /// #     avg_extrinsic_time: 187680.2.into(),
+    /// #     avg_storage_root_time: 83780.8.into(),
+    /// #     ratio,
+    /// #     percentage: 145.0108,
+    /// # };
+    /// let mut table = PerExtrTable::new();
+    /// table.push(entry(2.4501)).unwrap();
+    /// assert!(table.push(entry(f64::NAN)).is_err());
+    /// assert_eq!(table.raw_list().len(), 1);
+    /// ```
+    pub fn push(&mut self, entry: PerExtrTableEntry<'a>) -> Result<(), ValidationError> {
+        Self::validate_entry(&entry)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+    /// Creates a table of the given entries, `push`ing them in order. Fails on
+    /// the first entry which does not pass `validate_entry`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTable, PerExtrTableEntry};
+    /// # let entry = |extrinsic, ratio| PerExtrTableEntry {
+    /// #     pallet: "balances",
+    /// #     extrinsic,
+    /// #     avg_extrinsic_time: 100.0.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio,
+    /// #     percentage: 0.0,
+    /// # };
+    /// let entries = vec![entry("transfer", 2.4501), entry("set_balance", 1.0)];
+    /// let table = PerExtrTable::from_entries(entries).unwrap();
+    /// assert_eq!(table.entries().len(), 2);
+    /// ```
+    pub fn from_entries(
+        entries: impl IntoIterator<Item = PerExtrTableEntry<'a>>,
+    ) -> Result<Self, ValidationError> {
+        let mut table = PerExtrTable::new();
+        for entry in entries {
+            table.push(entry)?;
+        }
+
+        Ok(table)
+    }
+    /// Calculates the statistics of the ratios of all entries. Returns `None` if
+    /// the table is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// let summary = table.summary().unwrap();
+    /// assert_eq!(summary.count, 5);
+    /// assert_eq!(summary.min_ratio, 1.0);
+    /// assert_eq!(summary.max_ratio, 19.6006);
+    /// assert_eq!(summary.median_ratio, 2.4501);
+    /// assert_eq!(summary.max_pairwise_ratio_difference, 18.6006);
+    /// assert_eq!(summary.range_to_median_ratio, 7.9999);
+    /// ```
+    pub fn summary(&self) -> Option<Summary> {
+        self.summary_with_threshold(DEFAULT_RATIO_THRESHOLD)
+    }
+    /// Like `summary`, but counts the entries with a ratio above
+    /// `ratio_threshold` towards `Summary::fraction_above_threshold`, instead of
+    /// `DEFAULT_RATIO_THRESHOLD`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// assert_eq!(table.summary().unwrap().fraction_above_threshold, 0.6);
+    /// let summary = table.summary_with_threshold(10.0).unwrap();
+    /// assert_eq!(summary.fraction_above_threshold, 0.2);
+    /// ```
+    pub fn summary_with_threshold(&self, ratio_threshold: f64) -> Option<Summary> {
+        Summary::from_weighted(self.entries.iter().map(|e| (e.ratio, 1.0)), ratio_threshold)
+    }
+    /// The mean of the ratios of all entries, like `Summary::mean_ratio`, but
+    /// without calculating the other statistics. Non-finite ratios are ignored.
+    /// Returns `None` if no ratio is left.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// assert_eq!(table.mean_ratio(), Some(table.summary().unwrap().mean_ratio));
+    /// ```
+    pub fn mean_ratio(&self) -> Option<f64> {
+        self.raw_mean_ratio().map(|mean| mean.round_by(4))
+    }
+    /// The (population) variance of the ratios of all entries. Non-finite
+    /// ratios are ignored. Returns `None` if no ratio is left.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// let std_dev = table.summary().unwrap().std_dev_ratio;
+    /// assert!((table.variance().unwrap().sqrt() - std_dev).abs() < 0.0001);
+    /// ```
+    pub fn variance(&self) -> Option<f64> {
+        let mean = self.raw_mean_ratio()?;
+        let ratios = self.finite_ratios();
+
+        let variance = ratios.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / ratios.len() as f64;
+        Some(variance.round_by(4))
+    }
+    /// The median of the ratios of all entries, like `Summary::median_ratio`.
+    /// Non-finite ratios are ignored. Returns `None` if no ratio is left.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// assert_eq!(table.median_ratio(), Some(2.4501));
+    /// ```
+    pub fn median_ratio(&self) -> Option<f64> {
+        let mut ratios = self.finite_ratios();
+        if ratios.is_empty() {
+            return None;
+        }
+
+        ratios.sort_by(|a, b| SortOrder::Ascending.compare(*a, *b));
+
+        let middle = ratios.len() / 2;
+        let median = if ratios.len() % 2 == 0 {
+            (ratios[middle - 1] + ratios[middle]) / 2.0
+        } else {
+            ratios[middle]
+        };
+
+        Some(median.round_by(4))
+    }
+    fn finite_ratios(&self) -> Vec<f64> {
+        self.entries
+            .iter()
+            .map(|e| e.ratio)
+            .filter(|r| r.is_finite())
+            .collect()
+    }
+    fn raw_mean_ratio(&self) -> Option<f64> {
+        let ratios = self.finite_ratios();
+        if ratios.is_empty() {
+            return None;
+        }
+
+        Some(ratios.iter().sum::<f64>() / ratios.len() as f64)
+    }
+    /// Returns a table of the entries whose ratio lies between the `low_pct`
+    /// and the `high_pct` percentile (0-100, inclusive) of all ratios. The
+    /// percentiles are interpolated like `Summary::p90_ratio`. Entries keep
+    /// their order and entries with a non-finite ratio are left out.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// // The top 10% of the entries by ratio
+    /// let worst = table.percentile_range(90.0, 100.0);
+    /// assert_eq!(worst.extrinsic_names(), vec![("democracy", "delegate")]);
+    ///
+    /// assert_eq!(table.percentile_range(0.0, 50.0).entries().len(), 3);
+    /// ```
+    pub fn percentile_range(&self, low_pct: f64, high_pct: f64) -> PerExtrTable<'a> {
+        let mut ratios: Vec<(f64, f64)> = self
+            .finite_ratios()
+            .into_iter()
+            .map(|ratio| (ratio, 1.0))
+            .collect();
+        if ratios.is_empty() {
+            return PerExtrTable::new();
+        }
+
+        ratios.sort_by(|a, b| SortOrder::Ascending.compare(a.0, b.0));
+        let low = weighted_percentile(&ratios, low_pct);
+        let high = weighted_percentile(&ratios, high_pct);
+
+        PerExtrTable {
+            entries: self
+                .entries
+                .iter()
+                .filter(|e| e.ratio >= low && e.ratio <= high)
+                .map(|e| PerExtrTableEntry {
+                    pallet: e.pallet,
+                    extrinsic: e.extrinsic,
+                    avg_extrinsic_time: e.avg_extrinsic_time,
+                    avg_storage_root_time: e.avg_storage_root_time,
+                    ratio: e.ratio,
+                    percentage: e.percentage,
+                })
+                .collect(),
+        }
+    }
+    /// Like `summary`, but weights the ratio of each entry by how often the
+    /// extrinsic is called, keyed by `(pallet, extrinsic)`. Entries without a
+    /// frequency have a weight of `1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// let mut frequencies = HashMap::new();
+    /// frequencies.insert(("balances".to_string(), "transfer".to_string()), 10.0);
+    ///
+    /// let weighted = table.frequency_weighted_summary(&frequencies).unwrap();
+    /// assert!(weighted.mean_ratio < table.summary().unwrap().mean_ratio);
+    /// ```
+    pub fn frequency_weighted_summary(
+        &self,
+        frequencies: &HashMap<(String, String), f64>,
+    ) -> Option<Summary> {
+        Summary::from_weighted(
+            self.entries.iter().map(|e| {
+                let key = (e.pallet.to_string(), e.extrinsic.to_string());
+                (e.ratio, frequencies.get(&key).copied().unwrap_or(1.0))
+            }),
+            DEFAULT_RATIO_THRESHOLD,
+        )
+    }
+    /// Returns the average times (in nanoseconds) in the collapsed stack format
+    /// used by `flamegraph.pl` and `inferno`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// let data = table.to_flamegraph_data();
+    /// let lines: Vec<&str> = data.lines().collect();
+    /// assert_eq!(lines[0], "identity;add_registrar 76601");
+    /// assert_eq!(lines[1], "identity;add_registrar;storage_root 43874");
+    /// assert_eq!(lines.len(), 10);
+    /// ```
+    pub fn to_flamegraph_data(&self) -> String {
+        let mut data = String::new();
+        for entry in &self.entries {
+            data.push_str(&format!(
+                "{};{} {}\n{};{};storage_root {}\n",
+                entry.pallet,
+                entry.extrinsic,
+                entry.avg_extrinsic_time.to_ns().round() as u64,
+                entry.pallet,
+                entry.extrinsic,
+                entry.avg_storage_root_time.to_ns().round() as u64,
+            ));
+        }
+
+        data
+    }
+    /// Returns a self-contained HTML page of the table, in nanoseconds, with
+    /// sortable columns and a name filter.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let html = table.to_interactive_html();
+    /// assert!(html.starts_with("<!DOCTYPE html>"));
+    /// assert!(html.contains("<td>balances</td><td>transfer</td>"));
+    /// assert_eq!(html.matches("<tr>").count(), 6);
+    /// ```
+    pub fn to_interactive_html(&self) -> String {
+        const HEADERS: [&str; 6] = [
+            "Pallet",
+            "Extrinsic",
+            "Avg. Extrinsic Time (ns)",
+            "Avg. Storage Root Time (ns)",
+            "Extrinsic Time Ratio (1:x)",
+            "Extrinsic Time Increase (%)",
+        ];
+        // Sorts numerically if both cells are numbers, textually otherwise.
+        const SCRIPT: &str = r#"var t=document.getElementById("t"),b=t.tBodies[0],s=-1,d=1;
+document.getElementById("f").oninput=function(){var q=this.value.toLowerCase();
+for(var r of b.rows)r.style.display=(r.cells[0].textContent+"::"+r.cells[1].textContent).toLowerCase().includes(q)?"":"none";};
+t.tHead.rows[0].querySelectorAll("th").forEach(function(h,i){h.onclick=function(){d=s==i?-d:1;s=i;
+Array.from(b.rows).sort(function(x,y){var p=x.cells[i].textContent,q=y.cells[i].textContent,m=parseFloat(p),n=parseFloat(q);
+return d*(isNaN(m)||isNaN(n)?p.localeCompare(q):m-n);}).forEach(function(r){b.appendChild(r);});};});"#;
+
+        let mut html = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str("<title>Benchmark Ratios</title>\n");
+        html.push_str("<style>table{border-collapse:collapse}th,td{border:1px solid #999;padding:2px 6px}th{cursor:pointer}td+td+td{text-align:right}</style>\n");
+        html.push_str(
+            "</head>\n<body>\n<input id=\"f\" placeholder=\"Filter by pallet or extrinsic\">\n",
+        );
+        html.push_str("<table id=\"t\">\n<thead><tr>");
+        for header in HEADERS.iter() {
+            html.push_str(&format!("<th>{}</th>", header));
+        }
+        html.push_str("</tr></thead>\n<tbody>\n");
+
+        for entry in &self.entries {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(entry.pallet),
+                escape_html(entry.extrinsic),
+                entry.avg_extrinsic_time.to_ns(),
+                entry.avg_storage_root_time.to_ns(),
+                entry.ratio,
+                entry.percentage,
+            ));
+        }
+
+        html.push_str("</tbody>\n</table>\n<script>\n");
+        html.push_str(SCRIPT);
+        html.push_str("\n</script>\n</body>\n</html>\n");
+        html
+    }
+    /// Returns a Graphviz DOT graph with one node per pallet, sized by its mean
+    /// ratio. Pallets above `threshold` are filled and connected with each other.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let dot = table.to_dot_graph(2.0);
+    /// assert!(dot.starts_with("graph pallets {\n"));
+    /// assert!(dot.contains("\"identity\" [width=1, label=\"identity\\n1\"];"));
+    /// assert!(dot.contains("\"democracy\" -- \"balances\";"));
+    /// assert!(!dot.contains("\"treasury\" --"));
+    /// ```
+    pub fn to_dot_graph(&self, threshold: f64) -> String {
+        let pallets: Vec<(&str, f64)> = self
+            .group_by_pallet()
+            .into_iter()
+            .map(|(pallet, entries)| {
+                let mean = entries.iter().map(|e| e.ratio).sum::<f64>() / entries.len() as f64;
+                (pallet, mean.round_by(4))
+            })
+            .collect();
+
+        let escape = |pallet: &str| pallet.replace('\\', "\\\\").replace('"', "\\\"");
+
+        let mut dot = String::from("graph pallets {\n");
+        for &(pallet, mean) in &pallets {
+            let style = if mean > threshold {
+                ", style=filled"
+            } else {
+                ""
+            };
+            dot.push_str(&format!(
+                "    \"{0}\" [width={1}, label=\"{0}\\n{1}\"{2}];\n",
+                escape(pallet),
+                mean,
+                style
+            ));
+        }
+
+        let expensive: Vec<&str> = pallets
+            .iter()
+            .filter(|(_, mean)| *mean > threshold)
+            .map(|(pallet, _)| *pallet)
+            .collect();
+        for (index, first) in expensive.iter().enumerate() {
+            for second in &expensive[index + 1..] {
+                dot.push_str(&format!(
+                    "    \"{}\" -- \"{}\";\n",
+                    escape(first),
+                    escape(second)
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+    /// Returns an iterator over pages of up to `page_size` consecutive entries,
+    /// in the current order of the table. A `page_size` of zero yields no
+    /// pages.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let pages: Vec<_> = table.page_iter(2).collect();
+    /// assert_eq!(pages.len(), 3);
+    /// assert_eq!((pages[2].page_number, pages[2].total_pages), (3, 3));
+    /// assert_eq!(pages[2].entries.len(), 1);
+    /// ```
+    pub fn page_iter(&self, page_size: usize) -> PageIter<'a, '_> {
+        // `chunks` panics on a size of zero, an empty slice yields no chunks.
+        let entries = if page_size == 0 {
+            &self.entries[..0]
+        } else {
+            &self.entries[..]
+        };
+        let page_size = page_size.max(1);
+
+        PageIter {
+            chunks: entries.chunks(page_size),
+            page_number: 0,
+            total_pages: entries.len().div_ceil(page_size),
+        }
+    }
+    /// Calculates the ratio statistics of every window of `window_size`
+    /// consecutive entries, in the current order of the table.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// let windows = table.windowed_statistics(2);
+    /// assert_eq!(windows.len(), 4);
+    /// assert_eq!((windows[3].start_index, windows[3].end_index), (3, 4));
+    /// assert_eq!(windows[3].first_entry_pallet, "staking");
+    /// assert_eq!(windows[3].last_entry_pallet, "democracy");
+    /// assert_eq!(windows[2].window_mean_ratio, 2.4538);
+    /// ```
+    pub fn windowed_statistics(&self, window_size: usize) -> Vec<WindowStats<'a>> {
+        if window_size == 0 {
+            return vec![];
+        }
+
+        self.entries
+            .windows(window_size)
+            .enumerate()
+            .map(|(start_index, window)| {
+                let count = window.len() as f64;
+                let mean = window.iter().map(|e| e.ratio).sum::<f64>() / count;
+                let variance = window.iter().map(|e| (e.ratio - mean).powi(2)).sum::<f64>() / count;
+
+                WindowStats {
+                    start_index,
+                    end_index: start_index + window_size - 1,
+                    window_mean_ratio: mean.round_by(4),
+                    window_std_dev: variance.sqrt().round_by(4),
+                    first_entry_pallet: window[0].pallet,
+                    last_entry_pallet: window[window.len() - 1].pallet,
+                }
+            })
+            .collect()
+    }
+    /// Grades the table with the default `GradingCriteria`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::Grade;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// let report_card = table.report_card();
+    ///
+    /// // `democracy::delegate` exceeds the default ratio of 10.0
+    /// assert_eq!(report_card.grade, Grade::D);
+    /// assert_eq!(report_card.to_string(), "Grade D: 1/5 violations, mean ratio 5.47");
+    /// ```
+    pub fn report_card(&self) -> ReportCard {
+        self.report_card_with_criteria(&GradingCriteria::default())
+    }
+    /// Grades the table according to `criteria`. NaN and infinite ratios are
+    /// counted as violations, but are excluded from the mean ratio.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::{Grade, GradingCriteria};
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// let criteria = GradingCriteria {
+    ///     max_ratio: 2.0,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let report_card = table.report_card_with_criteria(&criteria);
+    /// assert_eq!(report_card.grade, Grade::F);
+    /// assert_eq!(report_card.violations_count, 3);
+    /// ```
+    pub fn report_card_with_criteria(&self, criteria: &GradingCriteria) -> ReportCard {
+        let total_count = self.entries.len();
+        let violations_count = self
+            .entries
+            .iter()
+            .filter(|e| !e.ratio.is_finite() || e.ratio > criteria.max_ratio)
+            .count();
+        let mean_ratio = self.summary().map_or(f64::NAN, |s| s.mean_ratio);
+
+        let share = if total_count == 0 {
+            0.0
+        } else {
+            violations_count as f64 / total_count as f64
+        };
+        let grade = [
+            (Grade::A, criteria.max_violation_share_a),
+            (Grade::B, criteria.max_violation_share_b),
+            (Grade::C, criteria.max_violation_share_c),
+            (Grade::D, criteria.max_violation_share_d),
+        ]
+        .iter()
+        .find(|(_, max_share)| share <= *max_share)
+        .map_or(Grade::F, |(grade, _)| *grade);
+
+        ReportCard {
+            grade,
+            violations_count,
+            total_count,
+            mean_ratio,
+            summary_text: format!(
+                "Grade {}: {}/{} violations, mean ratio {:.2}",
+                grade, violations_count, total_count, mean_ratio
+            ),
+        }
+    }
+    /// Returns owned copies of all entries.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let owned = collection.generate_ratio_table().unwrap().to_owned_entries();
+    /// drop(collection);
+    /// assert_eq!(owned.len(), 5);
+    /// ```
+    pub fn to_owned_entries(&self) -> Vec<OwnedPerExtrTableEntry> {
+        self.entries
+            .iter()
+            .map(OwnedPerExtrTableEntry::from)
+            .collect()
+    }
+    /// Whether the table contains an entry of the given pallet and extrinsic.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// assert!(table.contains("balances", "transfer"));
+    /// assert!(!table.contains("balances", "set_balance"));
+    /// ```
+    pub fn contains(&self, pallet: &str, extrinsic: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|e| e.pallet == pallet && e.extrinsic == extrinsic)
+    }
+    /// Whether the table contains any entry of the given pallet.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// assert!(table.contains_pallet("staking"));
+    /// assert!(!table.contains_pallet("timestamp"));
+    /// ```
+    pub fn contains_pallet(&self, pallet: &str) -> bool {
+        self.entries.iter().any(|e| e.pallet == pallet)
+    }
+    /// Returns the first entry of the given pallet and extrinsic.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// assert_eq!(table.get("balances", "transfer").unwrap().ratio, 2.4501);
+    /// assert!(table.get("balances", "set_balance").is_none());
+    /// ```
+    pub fn get(&self, pallet: &str, extrinsic: &str) -> Option<&PerExtrTableEntry<'a>> {
+        self.entries
+            .iter()
+            .find(|e| e.pallet == pallet && e.extrinsic == extrinsic)
+    }
+    /// Returns the first entry of the given pallet and extrinsic, mutably.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.get_mut("balances", "transfer").unwrap().ratio = 2.5;
+    /// assert_eq!(table.get("balances", "transfer").unwrap().ratio, 2.5);
+    /// ```
+    pub fn get_mut(&mut self, pallet: &str, extrinsic: &str) -> Option<&mut PerExtrTableEntry<'a>> {
+        self.entries
+            .iter_mut()
+            .find(|e| e.pallet == pallet && e.extrinsic == extrinsic)
+    }
+    /// Returns the entry at the given `index`, or `None` if out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// assert_eq!(table.entry_at(0).unwrap().extrinsic, "add_registrar");
+    /// assert!(table.entry_at(5).is_none());
+    /// ```
+    pub fn entry_at(&self, index: usize) -> Option<&PerExtrTableEntry<'a>> {
+        self.entries.get(index)
+    }
+    /// Returns a mutable reference to the entry at the given `index`, or
+    /// `None` if out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// table.entry_at_mut(0).unwrap().pallet = "renamed";
+    /// assert_eq!(table.raw_list()[0].0, "renamed");
+    /// ```
+    pub fn entry_at_mut(&mut self, index: usize) -> Option<&mut PerExtrTableEntry<'a>> {
+        self.entries.get_mut(index)
+    }
+    /// Swaps the entries at position `i` and `j`.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` are out of bounds, just like `Vec::swap`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// table.swap(0, 4);
+    /// assert_eq!(table.raw_list()[0].1, "delegate");
+    /// assert_eq!(table.raw_list()[4].1, "add_registrar");
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.entries.swap(i, j);
+    }
+    /// Removes all entries, keeping the allocated capacity.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.clear();
+    ///
+    /// assert!(table.entries().is_empty());
+    /// assert!(collection.generate_ratio_table().is_ok());
+    /// ```
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+    /// Like `clear`, but also frees the allocated capacity.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.clear_and_shrink();
+    /// assert!(table.entries().is_empty());
+    /// ```
+    pub fn clear_and_shrink(&mut self) {
+        self.entries.clear();
+        self.entries.shrink_to_fit();
+    }
+    /// Sorts the entries by ratio, lowest first. The sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// let ratios: Vec<f64> = table.raw_list().iter().map(|e| e.4).collect();
+    /// assert_eq!(ratios, vec![1.0, 1.8363, 2.4501, 2.4575, 19.6006]);
+    /// ```
+    pub fn sort_by_ratio(&mut self) {
+        self.sort_by_ratio_with_order(SortOrder::Ascending);
+    }
+    /// Sorts the entries by ratio, highest first. The sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio_desc();
+    /// assert_eq!(table.raw_list()[0].1, "delegate");
+    /// ```
+    pub fn sort_by_ratio_desc(&mut self) {
+        self.sort_by_ratio_with_order(SortOrder::Descending);
+    }
+    /// Sorts the entries by ratio in the given `order`. The sort is stable, so
+    /// entries with equal ratios keep their relative order in both directions.
+    /// Entries with a NaN or infinite ratio are always moved to the end.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::SortOrder;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio_with_order(SortOrder::Descending);
+    /// assert_eq!(table.raw_list()[0].4, 19.6006);
+    /// ```
+    pub fn sort_by_ratio_with_order(&mut self, order: SortOrder) {
+        warn_non_finite("ratio", self.entries.iter().map(|e| e.ratio));
+        self.entries.sort_by(|a, b| order.compare(a.ratio, b.ratio));
+    }
+    /// Sorts the entries by average extrinsic time, lowest first. The sort is
+    /// stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_avg_extrinsic_time();
+    /// assert_eq!(table.raw_list()[0].2, 76600.8);
+    /// ```
+    pub fn sort_by_avg_extrinsic_time(&mut self) {
+        self.sort_by_avg_extrinsic_time_with_order(SortOrder::Ascending);
+    }
+    /// Sorts the entries by average extrinsic time in the given `order`. The
+    /// sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::SortOrder;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_avg_extrinsic_time_with_order(SortOrder::Descending);
+    /// assert_eq!(table.raw_list()[0].2, 1501419.6);
+    /// ```
+    pub fn sort_by_avg_extrinsic_time_with_order(&mut self, order: SortOrder) {
+        warn_non_finite(
+            "avg_extrinsic_time",
+            self.entries.iter().map(|e| e.avg_extrinsic_time.to_ns()),
+        );
+        self.entries.sort_by(|a, b| {
+            order.compare(a.avg_extrinsic_time.to_ns(), b.avg_extrinsic_time.to_ns())
+        });
+    }
+    /// Sorts the entries by average storage root time, lowest first. The sort
+    /// is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_storage_root_time();
+    /// assert_eq!(table.raw_list()[0].3, 43874.4);
+    /// ```
+    pub fn sort_by_storage_root_time(&mut self) {
+        self.sort_by_storage_root_time_with_order(SortOrder::Ascending);
+    }
+    /// Sorts the entries by average storage root time, highest first, which
+    /// surfaces the extrinsics that stress the storage layer the most. The sort
+    /// is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_storage_root_time_desc();
+    /// assert_eq!(table.raw_list()[0].1, "delegate");
+    /// ```
+    pub fn sort_by_storage_root_time_desc(&mut self) {
+        self.sort_by_storage_root_time_with_order(SortOrder::Descending);
+    }
+    /// Sorts the entries by average storage root time in the given `order`. The
+    /// sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::SortOrder;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_storage_root_time_with_order(SortOrder::Descending);
+    /// assert_eq!(table.raw_list()[0].3, 464099.8);
+    /// ```
+    pub fn sort_by_storage_root_time_with_order(&mut self, order: SortOrder) {
+        warn_non_finite(
+            "avg_storage_root_time",
+            self.entries.iter().map(|e| e.avg_storage_root_time.to_ns()),
+        );
+        self.entries.sort_by(|a, b| {
+            order.compare(
+                a.avg_storage_root_time.to_ns(),
+                b.avg_storage_root_time.to_ns(),
+            )
+        });
+    }
+    /// Sorts the entries by total time (see `PerExtrTableEntry::total_time`),
+    /// highest first. The sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_total_time();
+    ///
+    /// assert_eq!(table.raw_list()[0].1, "delegate");
+    /// assert_eq!(table[("democracy", "delegate")].total_time().to_ns(), 1965519.4);
+    /// ```
+    pub fn sort_by_total_time(&mut self) {
+        warn_non_finite(
+            "total_time",
+            self.entries.iter().map(|e| e.total_time().to_ns()),
+        );
+        self.entries.sort_by(|a, b| {
+            SortOrder::Descending.compare(a.total_time().to_ns(), b.total_time().to_ns())
+        });
+    }
+    /// Sorts the entries by pallet name, then by extrinsic name. The sort is
+    /// stable. Recommended before writing the table to disk.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_extrinsic_name();
+    ///
+    /// let pallets: Vec<&str> = table.raw_list().iter().map(|e| e.0).collect();
+    /// assert_eq!(pallets, vec!["balances", "democracy", "identity", "staking", "treasury"]);
+    /// ```
+    pub fn sort_by_extrinsic_name(&mut self) {
+        self.entries
+            .sort_by(|a, b| (a.pallet, a.extrinsic).cmp(&(b.pallet, b.extrinsic)));
+    }
+    /// Groups the entries by pallet name, alphabetically, and sorts the entries
+    /// of each pallet by ratio, highest first. The sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (full, _) = ExtrinsicCollection::from_directory("tests/files/full/").unwrap();
+    /// # let (steps, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let collection = full.merge(steps);
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    ///
+    /// table.sort_by_pallet_then_ratio();
+    /// let list = table.raw_list();
+    /// assert_eq!((list[0].0, list[0].1), ("balances", "transfer"));
+    /// assert_eq!((list[1].0, list[1].1), ("balances", "set_balance_killing"));
+    /// assert_eq!(list[2].0, "democracy");
+    /// ```
+    pub fn sort_by_pallet_then_ratio(&mut self) {
+        self.entries.sort_by(|a, b| {
+            a.pallet
+                .cmp(b.pallet)
+                .then_with(|| SortOrder::Descending.compare(a.ratio, b.ratio))
+        });
+    }
+    /// Sorts the entries by `primary`, then by `secondary`, both ascending. The
+    /// sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    ///
+    /// table.sort_by_primary_then_secondary(|e| e.pallet.len(), |e| e.avg_storage_root_time);
+    /// assert_eq!(table.raw_list()[0].0, "balances");
+    /// ```
+    pub fn sort_by_primary_then_secondary<K1, K2, F1, F2>(&mut self, primary: F1, secondary: F2)
+    where
+        K1: Ord,
+        K2: PartialOrd,
+        F1: Fn(&PerExtrTableEntry<'a>) -> K1,
+        F2: Fn(&PerExtrTableEntry<'a>) -> K2,
+    {
+        self.entries.sort_by(|a, b| {
+            primary(a).cmp(&primary(b)).then_with(|| {
+                secondary(a)
+                    .partial_cmp(&secondary(b))
+                    .unwrap_or(Ordering::Equal)
+            })
+        });
+    }
+    /// Iterates over the entries sorted by ratio in the given `order`, like
+    /// `sort_by_ratio_with_order`, but without reordering the table itself.
+    /// Only the references to the entries are sorted.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::SortOrder;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let worst = table.iter_sorted_by_ratio(SortOrder::Descending).next().unwrap();
+    /// assert_eq!(worst.extrinsic, "delegate");
+    /// ```
+    pub fn iter_sorted_by_ratio(
+        &self,
+        order: SortOrder,
+    ) -> impl Iterator<Item = &PerExtrTableEntry<'a>> {
+        self.iter_sorted_by(|a, b| order.compare(a.ratio, b.ratio))
+    }
+    /// Like `iter_sorted_by_ratio`, but sorted by average extrinsic time.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::SortOrder;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let fastest = table.iter_sorted_by_avg_extrinsic_time(SortOrder::Ascending).next();
+    /// assert_eq!(fastest.unwrap().avg_extrinsic_time.to_ns(), 76600.8);
+    /// ```
+    pub fn iter_sorted_by_avg_extrinsic_time(
+        &self,
+        order: SortOrder,
+    ) -> impl Iterator<Item = &PerExtrTableEntry<'a>> {
+        self.iter_sorted_by(|a, b| {
+            order.compare(a.avg_extrinsic_time.to_ns(), b.avg_extrinsic_time.to_ns())
+        })
+    }
+    /// Like `iter_sorted_by_ratio`, but sorted by average storage root time.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::SortOrder;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let slowest = table.iter_sorted_by_storage_root_time(SortOrder::Descending).next();
+    /// assert_eq!(slowest.unwrap().avg_storage_root_time.to_ns(), 464099.8);
+    /// ```
+    pub fn iter_sorted_by_storage_root_time(
+        &self,
+        order: SortOrder,
+    ) -> impl Iterator<Item = &PerExtrTableEntry<'a>> {
+        self.iter_sorted_by(|a, b| {
+            order.compare(
+                a.avg_storage_root_time.to_ns(),
+                b.avg_storage_root_time.to_ns(),
+            )
+        })
+    }
+    /// Like `iter_sorted_by_ratio`, but sorted by total time (see
+    /// `PerExtrTableEntry::total_time`).
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::SortOrder;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let slowest = table.iter_sorted_by_total_time(SortOrder::Descending).next();
+    /// assert_eq!(slowest.unwrap().extrinsic, "delegate");
+    /// ```
+    pub fn iter_sorted_by_total_time(
+        &self,
+        order: SortOrder,
+    ) -> impl Iterator<Item = &PerExtrTableEntry<'a>> {
+        self.iter_sorted_by(|a, b| order.compare(a.total_time().to_ns(), b.total_time().to_ns()))
+    }
+    /// Like `sort_by_extrinsic_name`, but without reordering the table itself.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let pallets: Vec<&str> = table.iter_sorted_by_extrinsic_name().map(|e| e.pallet).collect();
+    /// assert_eq!(pallets, vec!["balances", "democracy", "identity", "staking", "treasury"]);
+    /// ```
+    pub fn iter_sorted_by_extrinsic_name(&self) -> impl Iterator<Item = &PerExtrTableEntry<'a>> {
+        self.iter_sorted_by(|a, b| (a.pallet, a.extrinsic).cmp(&(b.pallet, b.extrinsic)))
+    }
+    /// Like `sort_by_pallet_then_ratio`, but without reordering the table
+    /// itself.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let first = table.iter_sorted_by_pallet_then_ratio().next().unwrap();
+    /// assert_eq!(first.pallet, "balances");
+    /// ```
+    pub fn iter_sorted_by_pallet_then_ratio(&self) -> impl Iterator<Item = &PerExtrTableEntry<'a>> {
+        self.iter_sorted_by(|a, b| {
+            a.pallet
+                .cmp(b.pallet)
+                .then_with(|| SortOrder::Descending.compare(a.ratio, b.ratio))
+        })
+    }
+    fn iter_sorted_by(
+        &self,
+        compare: impl FnMut(&&PerExtrTableEntry<'a>, &&PerExtrTableEntry<'a>) -> Ordering,
+    ) -> std::vec::IntoIter<&PerExtrTableEntry<'a>> {
+        let mut entries: Vec<&PerExtrTableEntry<'a>> = self.entries.iter().collect();
+        entries.sort_by(compare);
+        entries.into_iter()
+    }
+    /// Returns each `(pallet, extrinsic)` pair which appears more than once,
+    /// together with the amount of times it appears. Pairs are listed in the
+    /// order of their first appearance.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (full, _) = ExtrinsicCollection::from_directory("tests/files/full/").unwrap();
+    /// # let (shortened, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// // Both directories contain results of the same extrinsics
+    /// let collection = full.merge(shortened);
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let duplicates = table.find_duplicates();
+    /// assert_eq!(duplicates.len(), 5);
+    /// assert!(duplicates.contains(&("balances", "transfer", 2)));
+    /// ```
+    pub fn find_duplicates(&self) -> Vec<(&'a str, &'a str, usize)> {
+        let mut counts: Vec<(&'a str, &'a str, usize)> = Vec::new();
+        for entry in &self.entries {
+            match counts
+                .iter_mut()
+                .find(|(p, e, _)| *p == entry.pallet && *e == entry.extrinsic)
+            {
+                Some((_, _, count)) => *count += 1,
+                None => counts.push((entry.pallet, entry.extrinsic, 1)),
+            }
+        }
+
+        counts.retain(|(_, _, count)| *count > 1);
+        counts
+    }
+    /// Removes duplicate `(pallet, extrinsic)` pairs, keeping the entry with the
+    /// highest ratio at the position of the first appearance. Ratios and
+    /// percentages are recalculated afterwards.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (full, _) = ExtrinsicCollection::from_directory("tests/files/full/").unwrap();
+    /// # let (shortened, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// // Both directories contain results of the same extrinsics
+    /// let collection = full.merge(shortened);
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    ///
+    /// table.dedup_keep_max_ratio();
+    /// assert!(table.find_duplicates().is_empty());
+    ///
+    /// let list = table.raw_list();
+    /// let transfer = list.iter().find(|e| e.1 == "transfer").unwrap();
+    /// assert_eq!(transfer.2, 187680.2);
+    /// ```
+    pub fn dedup_keep_max_ratio(&mut self) {
+        self.dedup_by_ratio(SortOrder::Descending);
+    }
+    /// Like `dedup_keep_max_ratio`, but keeps the entry with the lowest ratio.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (full, _) = ExtrinsicCollection::from_directory("tests/files/full/").unwrap();
+    /// # let (shortened, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// // Both directories contain results of the same extrinsics
+    /// let collection = full.merge(shortened);
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    ///
+    /// table.dedup_keep_min_ratio();
+    /// assert!(table.find_duplicates().is_empty());
+    ///
+    /// let list = table.raw_list();
+    /// let transfer = list.iter().find(|e| e.1 == "transfer").unwrap();
+    /// assert_eq!(transfer.2, 184602.4227);
+    /// ```
+    pub fn dedup_keep_min_ratio(&mut self) {
+        self.dedup_by_ratio(SortOrder::Ascending);
+    }
+    /// Removes duplicates, keeping the entry which comes first when sorted by
+    /// ratio in the given `order`.
+    fn dedup_by_ratio(&mut self, order: SortOrder) {
+        let mut kept: Vec<PerExtrTableEntry<'a>> = Vec::new();
+        for entry in self.entries.drain(..) {
+            match kept
+                .iter_mut()
+                .find(|e| e.pallet == entry.pallet && e.extrinsic == entry.extrinsic)
+            {
+                Some(existing) => {
+                    if order.compare(entry.ratio, existing.ratio) == Ordering::Less {
+                        *existing = entry;
+                    }
+                }
+                None => kept.push(entry),
+            }
+        }
+
+        self.entries = kept;
+        self.recalculate_ratios();
+    }
+    /// Scales the average times by `measured_ghz / reference_ghz` and
+    /// recalculates ratios and percentages.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// // Measured at 2 GHz, reference machine runs at 4 GHz
+    /// table.normalize_by_cpu_frequency(2.0, 4.0).unwrap();
+    /// assert_eq!(table.raw_list()[0].2, 38300.4);
+    /// assert_eq!(table.raw_list()[0].4, 1.0);
+    ///
+    /// assert!(table.normalize_by_cpu_frequency(2.0, 0.0).is_err());
+    /// ```
+    pub fn normalize_by_cpu_frequency(
+        &mut self,
+        measured_ghz: f64,
+        reference_ghz: f64,
+    ) -> Result<(), ValidationError> {
+        positive("measured_ghz", measured_ghz)?;
+        positive("reference_ghz", reference_ghz)?;
+
+        let factor = measured_ghz / reference_ghz;
+        self.scale_times(factor, factor);
+        Ok(())
+    }
+    /// Like `normalize_by_cpu_frequency`, but scales the storage root time by
+    /// `measured.memory_bandwidth_gbps / reference.memory_bandwidth_gbps`
+    /// instead, assuming that it is bound by memory rather than by the CPU.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::HardwareProfile;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// let measured = HardwareProfile {
+    ///     cpu_ghz: 2.0,
+    ///     memory_bandwidth_gbps: 10.0,
+    /// };
+    /// let reference = HardwareProfile {
+    ///     cpu_ghz: 4.0,
+    ///     memory_bandwidth_gbps: 20.0,
+    /// };
+    ///
+    /// table.normalize_by_hardware_profile(&measured, &reference).unwrap();
+    /// assert_eq!(table.raw_list()[0].2, 38300.4);
+    /// assert_eq!(table.raw_list()[0].3, 21937.2);
+    /// ```
+    pub fn normalize_by_hardware_profile(
+        &mut self,
+        measured: &HardwareProfile,
+        reference: &HardwareProfile,
+    ) -> Result<(), ValidationError> {
+        measured.validate()?;
+        reference.validate()?;
+
+        self.scale_times(
+            measured.cpu_ghz / reference.cpu_ghz,
+            measured.memory_bandwidth_gbps / reference.memory_bandwidth_gbps,
+        );
+        Ok(())
+    }
+    fn scale_times(&mut self, extrinsic_factor: f64, storage_root_factor: f64) {
+        for entry in &mut self.entries {
+            entry.avg_extrinsic_time = (entry.avg_extrinsic_time.to_ns() * extrinsic_factor)
+                .round_by(4)
+                .into();
+            entry.avg_storage_root_time = (entry.avg_storage_root_time.to_ns()
+                * storage_root_factor)
+                .round_by(4)
+                .into();
+        }
+
+        self.recalculate_ratios();
+    }
+    /// Subtracts the harness overhead (in nanoseconds) from the average times of
+    /// each entry, clamping at zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// table.apply_overhead_discount(1000.0, 500.0);
+    /// assert_eq!(table.raw_list()[0].2, 75600.8);
+    /// assert_eq!(table.raw_list()[0].3, 43374.4);
+    /// ```
+    pub fn apply_overhead_discount(
+        &mut self,
+        extrinsic_overhead_ns: f64,
+        storage_overhead_ns: f64,
+    ) {
+        for entry in &mut self.entries {
+            entry.avg_extrinsic_time = discount(entry.avg_extrinsic_time, extrinsic_overhead_ns);
+            entry.avg_storage_root_time =
+                discount(entry.avg_storage_root_time, storage_overhead_ns);
+        }
+
+        self.recalculate_ratios();
+    }
+    /// Replaces the ratio of each entry by the result of `f`. Operations which
+    /// recalculate ratios overwrite the transformed values.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    ///
+    /// // Extrinsic time relative to its own storage root time
+    /// table.apply_ratio_transform(|e| {
+    ///     e.avg_extrinsic_time.to_ns() / e.avg_storage_root_time.to_ns()
+    /// });
+    /// table.sort_by_ratio();
+    /// assert_eq!(table.entry_at(0).unwrap().extrinsic, "bond_extra");
+    /// ```
+    pub fn apply_ratio_transform<F>(&mut self, f: F)
+    where
+        F: Fn(&PerExtrTableEntry) -> f64,
+    {
+        for entry in &mut self.entries {
+            entry.ratio = f(entry);
+        }
+    }
+    /// Replaces the percentage of each entry by the result of `f`. See
+    /// `apply_ratio_transform`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    ///
+    /// // Time in percent of a 2 second block
+    /// table.apply_percentage_transform(|e| e.avg_extrinsic_time.to_ns() / 2e9 * 100.0);
+    /// assert!(table.raw_list().iter().all(|e| e.5 < 0.1));
+    /// ```
+    pub fn apply_percentage_transform<F>(&mut self, f: F)
+    where
+        F: Fn(&PerExtrTableEntry) -> f64,
+    {
+        for entry in &mut self.entries {
+            entry.percentage = f(entry);
+        }
+    }
+    /// Applies `f` to the ratio of each entry and recalculates the percentage
+    /// from the new ratio, keeping both consistent.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.map_ratios(|ratio| ratio * 2.0);
+    ///
+    /// let transfer = table.get("balances", "transfer").unwrap();
+    /// assert_eq!((transfer.ratio, transfer.percentage), (4.9002, 390.02));
+    /// ```
+    pub fn map_ratios<F>(&mut self, f: F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        for entry in &mut self.entries {
+            entry.ratio = f(entry.ratio).round_by(4);
+            entry.percentage = ((entry.ratio - 1.0) * 100.0).round_by(4);
+        }
+    }
+    /// Applies `f` to the average extrinsic time (in nanoseconds) of each
+    /// entry and recalculates the ratios and percentages from the new times.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.map_extrinsic_times(|time| time + 100_000.0);
+    ///
+    /// let transfer = table.get("balances", "transfer").unwrap();
+    /// assert_eq!(transfer.avg_extrinsic_time.to_ns(), 287680.2);
+    /// assert_eq!(transfer.ratio, 1.629);
+    /// ```
+    pub fn map_extrinsic_times<F>(&mut self, f: F)
+    where
+        F: Fn(f64) -> f64,
+    {
+        for entry in &mut self.entries {
+            entry.avg_extrinsic_time = f(entry.avg_extrinsic_time.to_ns()).round_by(4).into();
+        }
+
+        self.recalculate_ratios();
+    }
+    /// Recalculates the ratio and percentage of each entry based on the entry
+    /// with the lowest positive average extrinsic time.
+    fn recalculate_ratios(&mut self) {
+        let base = ratio_base(self.entries.iter().map(|e| e.avg_extrinsic_time.to_ns()));
+        if base.is_nan() && !self.entries.is_empty() {
+            warn!("no positive average extrinsic time, ratios are NaN");
+        }
+
+        for entry in &mut self.entries {
+            let time = entry.avg_extrinsic_time.to_ns();
+            entry.ratio = (time / base).round_by(4);
+            entry.percentage = ((time / base - 1.0) * 100.0).round_by(4);
+        }
+    }
+    /// The entries of the table, in their current order.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    ///
+    /// let (fastest, rest) = table.entries().split_at(1);
+    /// assert_eq!(fastest[0].extrinsic, "add_registrar");
+    /// assert_eq!(rest.len(), 4);
+    /// ```
+    pub fn entries(&self) -> &[PerExtrTableEntry<'a>] {
+        &self.entries
+    }
+    /// The pallet and extrinsic name of each entry, in the current order of the
+    /// table.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashSet;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (full, _) = ExtrinsicCollection::from_directory("tests/files/full/").unwrap();
+    /// # let (steps, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let (full, steps) = (full.generate_ratio_table().unwrap(), steps.generate_ratio_table().unwrap());
+    ///
+    /// let baseline: HashSet<_> = full.extrinsic_names().into_iter().collect();
+    /// let new: Vec<_> = steps
+    ///     .extrinsic_names()
+    ///     .into_iter()
+    ///     .filter(|name| !baseline.contains(name))
+    ///     .collect();
+    /// assert_eq!(new.len(), 2);
+    /// ```
+    pub fn extrinsic_names(&self) -> Vec<(&'a str, &'a str)> {
+        self.entries
+            .iter()
+            .map(|e| (e.pallet, e.extrinsic))
+            .collect()
+    }
+    /// Returns a list of the entries.
+    ///
+    /// Data ordered as:
+    /// - pallet
+    /// - extrinsic
+    /// - average extrinsic time
+    /// - average storage root time
+    /// - ratio
+    /// - percentage
+    ///
+    /// # Example output:
+    /// ```
+    /// vec![
+    ///     ("identity", "add_registrar", 1.0, 0.0),
+    ///     ("treasury", "tip_new", 1.8363, 83.6271),
+    ///     ("balances", "transfer", 2.4501, 145.0108),
+    /// ];
+    /// ```
+    pub fn raw_list(&self) -> Vec<(&str, &str, f64, f64, f64, f64)> {
+        self.entries
+            .iter()
+            .map(|e| {
+                (
+                    e.pallet,
+                    e.extrinsic,
+                    e.avg_extrinsic_time.to_ns(),
+                    e.avg_storage_root_time.to_ns(),
+                    e.ratio,
+                    e.percentage,
+                )
+            })
+            .collect()
+    }
+    /// Serializes the table to a compact JSON string.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// assert!(table.to_json_string().unwrap().starts_with("{\"entries\":["));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json_string(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string(self)?)
+    }
+    /// Serializes the table to an indented, human readable JSON string.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// assert!(table.to_json_pretty().unwrap().contains("\n  \"entries\": ["));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json_pretty(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+    /// Deserializes a table from JSON as produced by `to_json_string`. The
+    /// pallet and extrinsic names are borrowed from `s`. Each entry is
+    /// validated, just like with `push`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::PerExtrTable;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// let json = table.to_json_string().unwrap();
+    ///
+    /// let parsed = PerExtrTable::from_json_str(&json).unwrap();
+    /// assert_eq!(parsed.raw_list(), table.raw_list());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json_str(s: &'a str) -> Result<PerExtrTable<'a>, Error> {
+        let parsed: PerExtrTable<'a> = serde_json::from_str(s)?;
+
+        let mut table = PerExtrTable::new();
+        for entry in parsed.entries {
+            table.push(entry)?;
+        }
+
+        Ok(table)
+    }
+    fn build_table(&self, options: &PrintOptions) -> prettytable::Table {
+        Self::build_table_of(&self.entries, options)
+    }
+    fn build_table_of(
+        entries: &[PerExtrTableEntry<'a>],
+        options: &PrintOptions,
+    ) -> prettytable::Table {
+        let formatter = &options.time_formatter;
+        let times: Vec<BenchTime> = entries
+            .iter()
+            .flat_map(|entry| vec![entry.avg_extrinsic_time, entry.avg_storage_root_time])
+            .collect();
+        let unit = formatter.resolve_unit(&times);
+
+        let mut table = prettytable::Table::new();
+
+        // Header
+        table.add_row(row![
+            bc =>
+            "Pallet",
+            "Extrinsic",
+            format!("Avg. Extrinsic\nTime ({})", unit.label()),
+            format!("Avg. Storage\nRoot Time ({})", unit.label()),
+            "Extrinsic Time\nRatio (1:x)",
+            "Extrinsic Time\nIncrease (%)"
+        ]);
+
+        // Body
+        let add_entry = |table: &mut prettytable::Table, entry: &PerExtrTableEntry| {
+            table.add_row(row![
+                entry.pallet,
+                entry.extrinsic,
+                formatter.format(entry.avg_extrinsic_time, unit),
+                formatter.format(entry.avg_storage_root_time, unit),
+                entry.ratio,
+                entry.percentage,
+            ]);
+        };
+
+        if !options.group_by_pallet {
+            for entry in entries {
+                add_entry(&mut table, entry);
+            }
+
+            return table;
+        }
+
+        for (pallet, group) in Self::group_entries_by_pallet(entries) {
+            table.add_row(prettytable::Row::new(vec![prettytable::Cell::new(
+                &pallet.to_uppercase(),
+            )
+            .style_spec("bH6")]));
+
+            for entry in &group {
+                add_entry(&mut table, entry);
+            }
+
+            let count = group.len() as f64;
+            let mean = |value: fn(&PerExtrTableEntry) -> f64| {
+                (group.iter().map(|e| value(e)).sum::<f64>() / count).round_by(4)
+            };
+            table.add_row(row![
+                i =>
+                "Average",
+                format!("{} extrinsic(s)", group.len()),
+                formatter.format(mean(|e| e.avg_extrinsic_time.to_ns()).into(), unit),
+                formatter.format(mean(|e| e.avg_storage_root_time.to_ns()).into(), unit),
+                mean(|e| e.ratio),
+                mean(|e| e.percentage),
+            ]);
+        }
+
+        table
+    }
+    /// Returns the extrinsics with the lowest and highest ratio of each pallet,
+    /// sorted by the spread between the two, highest first. NaN and infinite
+    /// ratios are only picked if the pallet has no other entries.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTable, PerExtrTableEntry};
+    /// # let entry = |pallet, extrinsic, ratio| PerExtrTableEntry {
+    /// #     pallet,
+    /// #     extrinsic,
+    /// #     avg_extrinsic_time: 100.0.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio,
+    /// #     percentage: 0.0,
+    /// # };
+    /// let mut table = PerExtrTable::new();
+    /// table.push(entry("balances", "transfer", 2.4501)).unwrap();
+    /// table.push(entry("balances", "set_balance", 1.0)).unwrap();
+    /// table.push(entry("democracy", "delegate", 19.6006)).unwrap();
+    /// table.push(entry("democracy", "propose", 1.5)).unwrap();
+    ///
+    /// let extremes = table.best_and_worst_per_pallet();
+    /// assert_eq!(extremes[0].pallet, "democracy");
+    /// assert_eq!(extremes[0].worst.extrinsic, "delegate");
+    /// assert_eq!(extremes[0].spread, 18.1006);
+    /// assert_eq!(extremes[1].best.extrinsic, "set_balance");
+    /// ```
+    pub fn best_and_worst_per_pallet(&self) -> Vec<PalletExtremes<'_>> {
+        let mut extremes: Vec<PalletExtremes> = self
+            .group_by_pallet()
+            .into_iter()
+            .filter_map(|(pallet, entries)| {
+                let best = entries
+                    .iter()
+                    .min_by(|a, b| SortOrder::Ascending.compare(a.ratio, b.ratio))?;
+                let worst = entries
+                    .iter()
+                    .min_by(|a, b| SortOrder::Descending.compare(a.ratio, b.ratio))?;
+
+                Some(PalletExtremes {
+                    pallet,
+                    best,
+                    worst,
+                    spread: (worst.ratio - best.ratio).round_by(4),
+                })
+            })
+            .collect();
+
+        extremes.sort_by(|a, b| SortOrder::Descending.compare(a.spread, b.spread));
+        extremes
+    }
+    /// The pallet with the lowest mean ratio among its extrinsics. Ties go to
+    /// the pallet which appears first. Returns `None` for an empty table.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTable, PerExtrTableEntry};
+    /// # let entry = |pallet, extrinsic, avg_extrinsic_time: f64, ratio| PerExtrTableEntry {
+    /// #     pallet,
+    /// #     extrinsic,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio,
+    /// #     percentage: 0.0,
+    /// # };
+    /// let mut table = PerExtrTable::new();
+    /// table.push(entry("balances", "transfer", 200.0, 2.0)).unwrap();
+    /// table.push(entry("balances", "set_balance", 100.0, 1.0)).unwrap();
+    /// table.push(entry("democracy", "delegate", 1200.0, 12.0)).unwrap();
+    ///
+    /// assert_eq!(table.top_pallet_by_mean_ratio(), Some("balances"));
+    /// ```
+    pub fn top_pallet_by_mean_ratio(&self) -> Option<&'a str> {
+        self.pallet_by(SortOrder::Ascending, |entries| {
+            entries.iter().map(|e| e.ratio).sum::<f64>() / entries.len() as f64
+        })
+    }
+    /// The pallet with the highest ratio of any of its extrinsics. Ties go to
+    /// the pallet which appears first. Returns `None` for an empty table.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTable, PerExtrTableEntry};
+    /// # let entry = |pallet, extrinsic, avg_extrinsic_time: f64, ratio| PerExtrTableEntry {
+    /// #     pallet,
+    /// #     extrinsic,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio,
+    /// #     percentage: 0.0,
+    /// # };
+    /// let mut table = PerExtrTable::new();
+    /// table.push(entry("balances", "transfer", 200.0, 2.0)).unwrap();
+    /// table.push(entry("balances", "set_balance", 100.0, 1.0)).unwrap();
+    /// table.push(entry("democracy", "delegate", 1200.0, 12.0)).unwrap();
+    ///
+    /// assert_eq!(table.worst_pallet_by_max_ratio(), Some("democracy"));
+    /// ```
+    pub fn worst_pallet_by_max_ratio(&self) -> Option<&'a str> {
+        self.pallet_by(SortOrder::Descending, |entries| {
+            entries
+                .iter()
+                .map(|e| e.ratio)
+                .min_by(|a, b| SortOrder::Descending.compare(*a, *b))
+                .unwrap_or(f64::NAN)
+        })
+    }
+    /// The pallet with the lowest sum of the average extrinsic times of its
+    /// extrinsics. Ties go to the pallet which appears first. Returns `None`
+    /// for an empty table.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTable, PerExtrTableEntry};
+    /// # let entry = |pallet, extrinsic, avg_extrinsic_time: f64, ratio| PerExtrTableEntry {
+    /// #     pallet,
+    /// #     extrinsic,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio,
+    /// #     percentage: 0.0,
+    /// # };
+    /// let mut table = PerExtrTable::new();
+    /// table.push(entry("balances", "transfer", 200.0, 2.0)).unwrap();
+    /// table.push(entry("balances", "set_balance", 100.0, 1.0)).unwrap();
+    /// table.push(entry("democracy", "delegate", 1200.0, 12.0)).unwrap();
+    ///
+    /// assert_eq!(table.top_pallet_by_total_extrinsic_time(), Some("balances"));
+    /// ```
+    pub fn top_pallet_by_total_extrinsic_time(&self) -> Option<&'a str> {
+        self.pallet_by(SortOrder::Ascending, |entries| {
+            entries.iter().map(|e| e.avg_extrinsic_time.to_ns()).sum()
+        })
+    }
+    /// The pallet with the most extrinsics. Ties go to the pallet which appears
+    /// first. Returns `None` for an empty table.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{PerExtrTable, PerExtrTableEntry};
+    /// # let entry = |pallet, extrinsic, avg_extrinsic_time: f64, ratio| PerExtrTableEntry {
+    /// #     pallet,
+    /// #     extrinsic,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio,
+    /// #     percentage: 0.0,
+    /// # };
+    /// let mut table = PerExtrTable::new();
+    /// table.push(entry("balances", "transfer", 200.0, 2.0)).unwrap();
+    /// table.push(entry("balances", "set_balance", 100.0, 1.0)).unwrap();
+    /// table.push(entry("democracy", "delegate", 1200.0, 12.0)).unwrap();
+    ///
+    /// assert_eq!(table.worst_pallet_by_extrinsic_count(), Some("balances"));
+    /// ```
+    pub fn worst_pallet_by_extrinsic_count(&self) -> Option<&'a str> {
+        self.pallet_by(SortOrder::Descending, |entries| entries.len() as f64)
+    }
+    /// Returns the pallet whose entries have the first `metric` in `order`.
+    fn pallet_by(
+        &self,
+        order: SortOrder,
+        metric: fn(&[&PerExtrTableEntry<'a>]) -> f64,
+    ) -> Option<&'a str> {
+        self.group_by_pallet()
+            .into_iter()
+            .map(|(pallet, entries)| (pallet, metric(&entries)))
+            .min_by(|a, b| order.compare(a.1, b.1))
+            .map(|(pallet, _)| pallet)
+    }
+    /// Prints the result of `best_and_worst_per_pallet` to stdout, with one row
+    /// for the best and one row for the worst extrinsic of each pallet.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// table.print_extremes();
+    /// ```
+    pub fn print_extremes(&self) {
+        let mut table = prettytable::Table::new();
+
+        // Header
+        table.add_row(row![
+            bc =>
+            "Pallet",
+            "",
+            "Extrinsic",
+            "Extrinsic Time\nRatio (1:x)",
+            "Spread"
+        ]);
+
+        // Body
+        for extremes in self.best_and_worst_per_pallet() {
+            table.add_row(row![
+                extremes.pallet,
+                "Best",
+                extremes.best.extrinsic,
+                extremes.best.ratio,
+                extremes.spread,
+            ]);
+            table.add_row(row![
+                "",
+                "Worst",
+                extremes.worst.extrinsic,
+                extremes.worst.ratio,
+                "",
+            ]);
+        }
+
+        table.printstd();
+    }
+    /// Groups the entries by pallet, in the order in which each pallet first
+    /// appears. Entries keep their relative order within a pallet.
+    fn group_by_pallet(&self) -> Vec<(&'a str, Vec<&PerExtrTableEntry<'a>>)> {
+        Self::group_entries_by_pallet(&self.entries)
+    }
+    fn group_entries_by_pallet<'b>(
+        entries: &'b [PerExtrTableEntry<'a>],
+    ) -> Vec<(&'a str, Vec<&'b PerExtrTableEntry<'a>>)> {
+        let mut groups: Vec<(&'a str, Vec<&PerExtrTableEntry<'a>>)> = Vec::new();
+        for entry in entries {
+            match groups
+                .iter_mut()
+                .find(|(pallet, _)| *pallet == entry.pallet)
+            {
+                Some((_, entries)) => entries.push(entry),
+                None => groups.push((entry.pallet, vec![entry])),
+            }
+        }
+
+        groups
+    }
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    /// table.print();
+    /// ```
+    pub fn print(&self) {
+        self.print_with_options(&PrintOptions::default());
+    }
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::{PrintOptions, TimeFormatter, TimeUnit};
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// table.print_with_options(&PrintOptions {
+    ///     time_formatter: TimeFormatter {
+    ///         display_unit: TimeUnit::Auto,
+    ///         decimal_places: 2,
+    ///     },
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn print_with_options(&self, options: &PrintOptions) {
+        self.build_table(options).printstd();
+    }
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let mut table = collection.generate_ratio_table().unwrap();
+    /// table.sort_by_ratio();
+    /// table.print_csv();
+    /// ```
+    pub fn print_csv(&self) {
+        self.print_csv_with_options(&PrintOptions::default());
+    }
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::{PrintOptions, TimeFormatter, TimeUnit};
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// table.print_csv_with_options(&PrintOptions {
+    ///     time_formatter: TimeFormatter {
+    ///         display_unit: TimeUnit::Milliseconds,
+    ///         decimal_places: 6,
+    ///     },
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn print_csv_with_options(&self, options: &PrintOptions) {
+        write_table_csv(&self.build_table(options), stdout(), &CsvOptions::default()).unwrap();
+    }
+    /// Writes the table as comma separated values into `writer`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    ///
+    /// let mut csv = Vec::new();
+    /// table.write_csv(&mut csv).unwrap();
+    /// assert!(String::from_utf8(csv).unwrap().starts_with("Pallet,Extrinsic,"));
+    /// ```
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<(), Error> {
+        self.write_csv_with_options(writer, &CsvOptions::default())
+    }
+    /// Writes the table as CSV into `writer`, using the dialect of `options`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::{CsvOptions, LineEnding};
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+    /// let table = collection.generate_ratio_table().unwrap();
+    /// let options = CsvOptions {
+    ///     delimiter: ';',
+    ///     always_quote: true,
+    ///     line_ending: LineEnding::Windows,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut csv = Vec::new();
+    /// table.write_csv_with_options(&mut csv, &options).unwrap();
+    /// assert!(String::from_utf8(csv).unwrap().starts_with("\"Pallet\";\"Extrinsic\";"));
+    /// ```
+    pub fn write_csv_with_options<W: Write>(
+        &self,
+        writer: W,
+        options: &CsvOptions,
+    ) -> Result<(), Error> {
+        write_table_csv(&self.build_table(&PrintOptions::default()), writer, options)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StepIncrTable<'a> {
+    entries: Vec<StepIncrTableEntry<'a>>,
+}
+
+#[derive(Debug, Default)]
+pub struct StepIncrTableEntry<'a> {
+    pub pallet: &'a str,
+    pub extrinsic: &'a str,
+    /// Amount of steps as configured in the benchmark header.
+    pub steps: usize,
+    /// Amount of repeats as configured in the benchmark header.
+    pub repeats: usize,
+    /// Names of the components, in the order of `StepIncr::input_vars`.
+    pub input_var_names: &'a [String],
+    /// Amount of distinct steps the entry must have, if known. Unlike `steps`,
+    /// which is configured per component, this is compared with the length of
+    /// `step_incrs` by `validate`, catching steps lost during parsing.
+    pub expected_steps: Option<usize>,
+    pub step_incrs: Vec<StepIncr<'a>>,
+}
+
+#[derive(Debug)]
+pub struct StepIncr<'a> {
+    pub input_vars: &'a Vec<u64>,
+    pub avg_extrinsic_time: BenchTime,
+    pub avg_storage_root_time: BenchTime,
+    pub ratio: f64,
+    pub extrinsic_incr_percentage: f64,
+    pub storage_root_incr_percentage: f64,
+}
+
+impl<'a> StepIncr<'a> {
+    /// The sum of the average extrinsic time and the average storage root time.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::StepIncr;
+    /// let step = StepIncr {
+    ///     input_vars: &vec![10],
+    ///     avg_extrinsic_time: 130751.0.into(),
+    ///     avg_storage_root_time: 96830.6667.into(),
+    ///     ratio: 1.0385,
+    ///     extrinsic_incr_percentage: 3.8495,
+    ///     storage_root_incr_percentage: 13.2279,
+    /// };
+    ///
+    /// assert_eq!(step.total_time().to_ns(), 227581.6667);
+    /// ```
+    pub fn total_time(&self) -> BenchTime {
+        (self.avg_extrinsic_time.to_ns() + self.avg_storage_root_time.to_ns())
+            .round_by(4)
+            .into()
+    }
+}
+
+impl<'a> StepIncrTableEntry<'a> {
+    /// Checks whether the entry can be inserted into the table. Requirements:
+    /// - all steps have the same amount of input variables
+    /// - times are finite and not negative
+    /// - the amount of steps matches `expected_steps`, if set
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (small, large) = (vec![1, 1000], vec![496, 1000]);
+    /// # let step = |input_vars, avg_extrinsic_time: f64| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 62925.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let mut entry = StepIncrTableEntry {
+    ///     pallet: "balances",
+    ///     extrinsic: "set_balance_killing",
+    ///     step_incrs: vec![step(&small, 122488.6667), step(&large, 105916.0)],
+    ///     ..Default::default()
+    /// };
+    /// assert!(entry.validate().is_ok());
+    ///
+    /// entry.expected_steps = Some(3);
+    /// assert!(entry.validate().is_err());
+    ///
+    /// entry.expected_steps = Some(2);
+    /// entry.step_incrs[1].avg_extrinsic_time = f64::NAN.into();
+    /// assert!(entry.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(expected) = self.expected_steps {
+            if self.step_incrs.len() != expected {
+                return Err(MissingSteps {
+                    expected,
+                    found: self.step_incrs.len(),
+                });
+            }
+        }
+
+        let expected = self.step_incrs.first().map(|s| s.input_vars.len());
+
+        for step in &self.step_incrs {
+            if let Some(expected) = expected {
+                if step.input_vars.len() != expected {
+                    return Err(InconsistentComponents {
+                        expected,
+                        found: step.input_vars.len(),
+                    });
+                }
+            }
+
+            let times = [
+                ("avg_extrinsic_time", step.avg_extrinsic_time.to_ns()),
+                ("avg_storage_root_time", step.avg_storage_root_time.to_ns()),
+            ];
+            for &(field, value) in &times {
+                if !value.is_finite() {
+                    return Err(NonFinite { field, value });
+                }
+                if value < 0.0 {
+                    return Err(Negative { field, value });
+                }
+            }
+        }
+
+        Ok(())
+    }
+    /// Calculates the ratio and increase percentages of each step based on the
+    /// smallest positive extrinsic and storage root times.
+    pub(crate) fn recalculate_percentages(&mut self) {
+        let extrinsic_base =
+            ratio_base(self.step_incrs.iter().map(|s| s.avg_extrinsic_time.to_ns()));
+        let storage_root_base = ratio_base(
+            self.step_incrs
+                .iter()
+                .map(|s| s.avg_storage_root_time.to_ns()),
+        );
+
+        for entry in &mut self.step_incrs {
+            let extrinsic_time = entry.avg_extrinsic_time.to_ns();
+            let storage_root_time = entry.avg_storage_root_time.to_ns();
+            entry.ratio = (extrinsic_time / extrinsic_base).round_by(4);
+            entry.extrinsic_incr_percentage =
+                ((extrinsic_time / extrinsic_base - 1.0) * 100.0).round_by(4);
+            entry.storage_root_incr_percentage =
+                ((storage_root_time / storage_root_base - 1.0) * 100.0).round_by(4);
+        }
+    }
+    /// Calculates the increase of the total time (see `StepIncr::total_time`)
+    /// of `step` compared to the `baseline_step`, in percent. Returns NaN if
+    /// the entry has no steps.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (small, large) = (vec![1], vec![10]);
+    /// # let step = |input_vars, avg_extrinsic_time: f64, avg_storage_root_time: f64| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: avg_storage_root_time.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let mut entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![step(&small, 150.0, 50.0), step(&large, 160.0, 20.0)],
+    ///     ..Default::default()
+    /// };
+    /// entry.step_incrs[1].extrinsic_incr_percentage = 6.6667;
+    ///
+    /// // The baseline step is not the one with the lowest total time
+    /// assert_eq!(entry.total_incr_percentage(&entry.step_incrs[0]), 0.0);
+    /// assert_eq!(entry.total_incr_percentage(&entry.step_incrs[1]), -10.0);
+    /// ```
+    pub fn total_incr_percentage(&self, step: &StepIncr) -> f64 {
+        match self.baseline_step() {
+            Some(base) => {
+                let base = base.total_time().to_ns();
+                ((step.total_time().to_ns() / base - 1.0) * 100.0).round_by(4)
+            }
+            None => f64::NAN,
+        }
+    }
+    /// Returns the step all increase percentages are relative to, which is the
+    /// first step without an extrinsic time increase. Falls back to the step
+    /// with the lowest extrinsic time if the percentages were not calculated.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (small, large) = (vec![1], vec![10]);
+    /// # let step = |input_vars, avg_extrinsic_time: f64| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 0.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let mut entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![step(&large, 130751.0), step(&small, 125904.3333)],
+    ///     ..Default::default()
+    /// };
+    /// entry.step_incrs[0].extrinsic_incr_percentage = 3.8495;
+    ///
+    /// let baseline = entry.baseline_step().unwrap();
+    /// assert_eq!(baseline.input_vars, &vec![1]);
+    /// ```
+    pub fn baseline_step(&self) -> Option<&StepIncr<'a>> {
+        self.step_incrs
+            .iter()
+            .find(|s| s.extrinsic_incr_percentage == 0.0)
+            .or_else(|| {
+                self.step_incrs.iter().min_by(|a, b| {
+                    SortOrder::Ascending
+                        .compare(a.avg_extrinsic_time.to_ns(), b.avg_extrinsic_time.to_ns())
+                })
+            })
+    }
+    /// Returns the step with the highest extrinsic time increase. NaN and
+    /// infinite percentages are only considered if no other step exists.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (one, ten, nineteen) = (vec![1], vec![10], vec![19]);
+    /// # let step = |input_vars, extrinsic_incr_percentage| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: 100.0.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![step(&ten, 3.8495), step(&nineteen, 7.9605), step(&one, 0.0)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(entry.peak_step().unwrap().input_vars, &vec![19]);
+    /// ```
+    pub fn peak_step(&self) -> Option<&StepIncr<'a>> {
+        self.step_incrs.iter().min_by(|a, b| {
+            SortOrder::Descending.compare(a.extrinsic_incr_percentage, b.extrinsic_incr_percentage)
+        })
+    }
+    /// Returns the interpolated percentile (0-100) of the step's extrinsic time
+    /// among the extrinsic times of all steps.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (one, ten, nineteen) = (vec![1], vec![10], vec![19]);
+    /// # let step = |input_vars, avg_extrinsic_time: f64| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![step(&nineteen, 300.0), step(&one, 100.0), step(&ten, 200.0)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(entry.percentile_of_step(&entry.step_incrs[0]), 100.0);
+    /// assert_eq!(entry.percentile_of_step(&entry.step_incrs[1]), 0.0);
+    /// assert_eq!(entry.percentile_of_step(&entry.step_incrs[2]), 50.0);
+    /// assert_eq!(entry.percentile_of_step(&step(&ten, 250.0)), 75.0);
+    /// ```
+    pub fn percentile_of_step(&self, step: &StepIncr) -> f64 {
+        let time = step.avg_extrinsic_time.to_ns();
+        if !time.is_finite() {
+            return f64::NAN;
+        }
+
+        let mut times: Vec<f64> = self
+            .step_incrs
+            .iter()
+            .map(|s| s.avg_extrinsic_time.to_ns())
+            .filter(|t| t.is_finite())
+            .collect();
+        times.sort_by(|a, b| SortOrder::Ascending.compare(*a, *b));
 
-impl<'a> PerExtrTable<'a> {
-    pub fn new() -> Self {
-        PerExtrTable {
-            entries: Vec::new(),
+        match (times.first(), times.last()) {
+            (Some(min), _) if time <= *min => return 0.0,
+            (_, Some(max)) if time >= *max => return 100.0,
+            (None, _) | (_, None) => return 0.0,
+            _ => {}
         }
+
+        // Index of the first time which is not lower, guaranteed to be > 0.
+        let upper = times.partition_point(|t| *t < time);
+        let rank = if times[upper] == time {
+            upper as f64
+        } else {
+            let (low, high) = (times[upper - 1], times[upper]);
+            (upper - 1) as f64 + (time - low) / (high - low)
+        };
+
+        (rank / (times.len() - 1) as f64 * 100.0).round_by(4)
     }
-    pub(crate) fn push(&mut self, entry: PerExtrTableEntry<'a>) {
-        self.entries.push(entry);
+    /// Returns the highest increase of the extrinsic time per unit of the
+    /// component at `component_index`, between consecutive component values of
+    /// steps which only differ in that component.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (one, ten, nineteen) = (vec![1], vec![10], vec![19]);
+    /// # let step = |input_vars, avg_extrinsic_time: f64| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![
+    ///         step(&nineteen, 135927.0),
+    ///         step(&one, 125904.3333),
+    ///         step(&ten, 130751.0),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(entry.max_step_growth_rate(0), Some(575.1111));
+    /// assert_eq!(entry.max_step_growth_rate(1), None);
+    /// ```
+    pub fn max_step_growth_rate(&self, component_index: usize) -> Option<f64> {
+        let series = self.component_series(component_index);
+
+        series
+            .iter()
+            .flat_map(|points| points.windows(2))
+            .filter(|pair| pair[0].0 != pair[1].0)
+            .map(|pair| {
+                let prev = self.step_incrs[pair[0].1].avg_extrinsic_time.to_ns();
+                let curr = self.step_incrs[pair[1].1].avg_extrinsic_time.to_ns();
+                (curr - prev) / (pair[1].0 - pair[0].0) as f64
+            })
+            .fold(None, |max, rate| {
+                Some(max.map_or(rate, |max: f64| max.max(rate)))
+            })
+            .map(|max| max.round_by(4))
     }
-    pub fn sort_by_ratio(&mut self) {
-        self.entries
-            .sort_by(|a, b| a.ratio.partial_cmp(&b.ratio).unwrap_or(Ordering::Equal));
+    /// The lowest and highest value of the component at `component_index`
+    /// among all steps, or `None` if no step has that component.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (small, large) = (vec![1, 1000], vec![496, 1000]);
+    /// # let step = |input_vars| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: 100.0.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let entry = StepIncrTableEntry {
+    ///     pallet: "balances",
+    ///     extrinsic: "set_balance_killing",
+    ///     step_incrs: vec![step(&large), step(&small)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(entry.component_value_range(0), Some((1, 496)));
+    /// assert_eq!(entry.component_value_range(1), Some((1000, 1000)));
+    /// assert_eq!(entry.component_value_range(2), None);
+    /// ```
+    pub fn component_value_range(&self, component_index: usize) -> Option<(u64, u64)> {
+        self.step_incrs
+            .iter()
+            .filter_map(|s| s.input_vars.get(component_index))
+            .fold(None, |range, &value| match range {
+                Some((min, max)) => Some((u64::min(min, value), u64::max(max, value))),
+                None => Some((value, value)),
+            })
     }
-    /// Returns a list of the entries.
+    /// Returns the steps ordered by the value of the component at
+    /// `component_index`, lowest first. The sort is stable. Steps without that
+    /// component are placed last.
     ///
-    /// Data ordered as:
-    /// - pallet
-    /// - extrinsic
-    /// - average extrinsic time
-    /// - average storage root time
-    /// - ratio
-    /// - percentage
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (one, ten, nineteen) = (vec![1], vec![10], vec![19]);
+    /// # let step = |input_vars| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: 100.0.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![step(&ten), step(&nineteen), step(&one)],
+    ///     ..Default::default()
+    /// };
     ///
-    /// # Example output:
+    /// let sorted: Vec<&[u64]> = entry
+    ///     .steps_sorted_by_component(0)
+    ///     .iter()
+    ///     .map(|s| s.input_vars.as_slice())
+    ///     .collect();
+    /// assert_eq!(sorted, [[1], [10], [19]]);
     /// ```
-    /// vec![
-    ///     ("identity", "add_registrar", 1.0, 0.0),
-    ///     ("treasury", "tip_new", 1.8363, 83.6271),
-    ///     ("balances", "transfer", 2.4501, 145.0108),
-    /// ];
+    pub fn steps_sorted_by_component(&self, component_index: usize) -> Vec<&StepIncr<'a>> {
+        let mut steps: Vec<&StepIncr<'a>> = self.step_incrs.iter().collect();
+        steps.sort_by_key(|s| Self::component_key(s, component_index));
+        steps
+    }
+    /// Sorts the steps in place, like `steps_sorted_by_component`.
+    ///
+    /// # Example
     /// ```
-    pub fn raw_list(&self) -> Vec<(&str, &str, f64, f64, f64, f64)> {
-        self.entries
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (one, ten) = (vec![1], vec![10]);
+    /// # let step = |input_vars| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: 100.0.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let mut entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![step(&ten), step(&one)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// entry.sort_steps_by_component(0);
+    /// assert_eq!(entry.step_incrs[0].input_vars, &one);
+    /// ```
+    pub fn sort_steps_by_component(&mut self, component_index: usize) {
+        self.step_incrs
+            .sort_by_key(|s| Self::component_key(s, component_index));
+    }
+    fn component_key(step: &StepIncr, component_index: usize) -> (bool, u64) {
+        match step.input_vars.get(component_index) {
+            Some(component) => (false, *component),
+            None => (true, 0),
+        }
+    }
+    /// Ranks the components by the correlation of their values with the extrinsic
+    /// time, highest first, as `(component index, correlation)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// let entry = table.entries().iter().find(|e| e.extrinsic == "set_balance_killing").unwrap();
+    ///
+    /// // `e` is the same in all steps, `u` correlates negatively
+    /// let rank = entry.component_sensitivity_rank();
+    /// assert_eq!(rank.len(), 1);
+    /// assert_eq!(rank[0].0, 0);
+    /// assert!(rank[0].1 < 0.0);
+    /// ```
+    pub fn component_sensitivity_rank(&self) -> Vec<(usize, f64)> {
+        let component_count = self.step_incrs.first().map_or(0, |s| s.input_vars.len());
+
+        let mut rank: Vec<(usize, f64)> = (0..component_count)
+            .filter_map(|index| {
+                let points: Vec<(f64, f64)> = self
+                    .step_incrs
+                    .iter()
+                    .filter_map(|s| {
+                        s.input_vars
+                            .get(index)
+                            .map(|c| (*c as f64, s.avg_extrinsic_time.to_ns()))
+                    })
+                    .collect();
+
+                pearson_correlation(&points).map(|r| (index, r.round_by(4)))
+            })
+            .collect();
+
+        // Stable, so ties keep the order of the component indices
+        rank.sort_by(|a, b| SortOrder::Descending.compare(a.1, b.1));
+        rank
+    }
+    /// Predicts the extrinsic time at `value` of the component at
+    /// `component_index` with a least squares fit. Only meant for sanity checks.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTableEntry};
+    /// let (one, ten, nineteen) = (vec![1], vec![10], vec![19]);
+    /// # let step = |input_vars, avg_extrinsic_time: f64| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: avg_extrinsic_time.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![step(&one, 1100.0), step(&ten, 2000.0), step(&nineteen, 2900.0)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let prediction = entry.extrapolate_extrinsic_time(0, 55.0).unwrap();
+    /// assert_eq!(prediction.extrinsic_time.to_ns(), 6500.0);
+    /// assert_eq!(prediction.extrapolation_distance, 2.0);
+    /// assert_eq!(entry.extrapolate_extrinsic_time(1, 55.0), None);
+    /// ```
+    pub fn extrapolate_extrinsic_time(
+        &self,
+        component_index: usize,
+        value: f64,
+    ) -> Option<Extrapolation> {
+        let (intercept, slope) = self.fit_linear_regression(component_index)?;
+        let (min, max) = self.component_value_range(component_index)?;
+        let (min, max) = (min as f64, max as f64);
+
+        let distance = if value < min {
+            min - value
+        } else if value > max {
+            value - max
+        } else {
+            0.0
+        };
+
+        Some(Extrapolation {
+            extrinsic_time: (intercept + slope * value).round_by(4).into(),
+            extrapolation_distance: (distance / (max - min)).round_by(4),
+        })
+    }
+    /// Least squares fit of the extrinsic times of all steps to the component
+    /// at `component_index`, as `(intercept, slope)`.
+    fn fit_linear_regression(&self, component_index: usize) -> Option<(f64, f64)> {
+        let points: Vec<(f64, f64)> = self
+            .step_incrs
             .iter()
-            .map(|e| {
-                (
-                    e.pallet,
-                    e.extrinsic,
-                    e.avg_extrinsic_time,
-                    e.avg_storage_root_time,
-                    e.ratio,
-                    e.percentage,
-                )
+            .filter_map(|s| {
+                s.input_vars
+                    .get(component_index)
+                    .map(|c| (*c as f64, s.avg_extrinsic_time.to_ns()))
             })
-            .collect()
+            .collect();
+        if points.is_empty() {
+            return None;
+        }
+
+        let count = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+        let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        if variance == 0.0 {
+            return None;
+        }
+
+        let covariance: f64 = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let slope = covariance / variance;
+
+        Some((mean_y - slope * mean_x, slope))
     }
-    fn build_table(&self) -> prettytable::Table {
-        let mut table = prettytable::Table::new();
+    /// Returns the steps whose extrinsic time is lower than that of the step
+    /// with the next lower value of `component_index`, among the steps which
+    /// only differ in that component.
+    /// Groups the steps by the values of all components other than the one at
+    /// `component_index`, as `(component value, step index)` pairs sorted by
+    /// the component value. Steps without that component are left out.
+    fn component_series(&self, component_index: usize) -> Vec<Vec<(u64, usize)>> {
+        let mut series: BTreeMap<Vec<u64>, Vec<(u64, usize)>> = BTreeMap::new();
+        for (index, step) in self.step_incrs.iter().enumerate() {
+            if let Some(component) = step.input_vars.get(component_index) {
+                let mut others = step.input_vars.clone();
+                others.remove(component_index);
+                series.entry(others).or_default().push((*component, index));
+            }
+        }
 
-        // Header
-        table.add_row(row![
-            bc =>
-            "Pallet",
-            "Extrinsic",
-            "Avg. Extrinsic\nTime",
-            "Avg. Storage\nRoot Time",
-            "Extrinsic Time\nRatio (1:x)",
-            "Extrinsic Time\nIncrease (%)"
-        ]);
+        series
+            .into_values()
+            .map(|mut points| {
+                points.sort_by_key(|(component, _)| *component);
+                points
+            })
+            .collect()
+    }
+    fn monotonicity_violations(&self, component_index: usize) -> Vec<MonotonicityViolation<'a>> {
+        let mut violations = vec![];
+        for points in self.component_series(component_index) {
+            for pair in points.windows(2) {
+                let prev = &self.step_incrs[pair[0].1];
+                let curr = &self.step_incrs[pair[1].1];
 
-        // Body
-        for entry in self.raw_list() {
-            table.add_row(row![entry.0, entry.1, entry.2, entry.3, entry.4, entry.5,]);
+                if pair[0].0 != pair[1].0 && curr.avg_extrinsic_time < prev.avg_extrinsic_time {
+                    violations.push(MonotonicityViolation {
+                        pallet: self.pallet,
+                        extrinsic: self.extrinsic,
+                        component_index,
+                        violation_step_index: pair[1].1,
+                        prev_time: prev.avg_extrinsic_time,
+                        curr_time: curr.avg_extrinsic_time,
+                    });
+                }
+            }
         }
 
-        table
+        violations
     }
-    pub fn print(&self) {
-        self.build_table().printstd();
+    /// Highest extrinsic time increase among all steps.
+    fn max_extrinsic_incr_percentage(&self) -> Option<f64> {
+        self.step_incrs
+            .iter()
+            .map(|s| s.extrinsic_incr_percentage)
+            .fold(None, |max, p| Some(max.map_or(p, |max: f64| max.max(p))))
     }
-    pub fn print_csv(&self) {
-        self.build_table().to_csv(stdout()).unwrap();
+    /// Highest storage root time increase among all steps.
+    fn max_storage_root_incr_percentage(&self) -> Option<f64> {
+        self.step_incrs
+            .iter()
+            .map(|s| s.storage_root_incr_percentage)
+            .fold(None, |max, p| Some(max.map_or(p, |max: f64| max.max(p))))
+    }
+    /// Coefficient of variation (standard deviation divided by the mean) of the
+    /// average extrinsic times of all steps.
+    fn extrinsic_time_variation(&self) -> Option<f64> {
+        if self.step_incrs.is_empty() {
+            return None;
+        }
+
+        let count = self.step_incrs.len() as f64;
+        let mean = self
+            .step_incrs
+            .iter()
+            .map(|s| s.avg_extrinsic_time.to_ns())
+            .sum::<f64>()
+            / count;
+        let variance = self
+            .step_incrs
+            .iter()
+            .map(|s| (s.avg_extrinsic_time.to_ns() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Some(variance.sqrt() / mean)
     }
 }
 
-#[derive(Debug)]
-pub struct StepIncrTable<'a> {
-    entries: Vec<StepIncrTableEntry<'a>>,
+/// Step growth statistics of all extrinsics of a pallet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepPalletSummary<'a> {
+    pub extrinsic_count: usize,
+    /// Amount of steps of all extrinsics combined.
+    pub total_steps: usize,
+    /// Average of the highest extrinsic time increase of each extrinsic.
+    pub avg_max_extrinsic_percentage: f64,
+    /// Average of the highest storage root time increase of each extrinsic.
+    pub avg_max_storage_root_percentage: f64,
+    /// The extrinsic with the highest coefficient of variation of its extrinsic
+    /// times across all steps.
+    pub most_variable_extrinsic: &'a str,
 }
 
-#[derive(Debug, Default)]
-pub(crate) struct StepIncrTableEntry<'a> {
+/// An extrinsic time predicted by `StepIncrTableEntry::extrapolate_extrinsic_time`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extrapolation {
+    pub extrinsic_time: BenchTime,
+    /// Distance of the component value from the measured range, in units of
+    /// that range. Zero within the measured range; the higher, the less the
+    /// prediction can be trusted.
+    pub extrapolation_distance: f64,
+}
+
+/// An extrinsic which was benchmarked with fewer steps or repeats than
+/// required, making its averages unreliable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowSampleWarning<'a> {
     pub pallet: &'a str,
     pub extrinsic: &'a str,
-    pub step_incrs: Vec<StepIncr<'a>>,
+    pub actual_steps: usize,
+    pub actual_repeats: usize,
 }
 
-#[derive(Debug)]
-pub(crate) struct StepIncr<'a> {
-    pub input_vars: &'a Vec<u64>,
-    pub avg_extrinsic_time: f64,
-    pub avg_storage_root_time: f64,
-    pub ratio: f64,
-    pub extrinsic_incr_percentage: f64,
-    pub storage_root_incr_percentage: f64,
+/// A step at which the extrinsic time decreased although a component
+/// increased, which usually indicates a bug in the benchmark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonotonicityViolation<'a> {
+    pub pallet: &'a str,
+    pub extrinsic: &'a str,
+    /// Position of the increased component in the input variables.
+    pub component_index: usize,
+    /// Position of the step with the decreased time in `step_incrs`.
+    pub violation_step_index: usize,
+    /// Extrinsic time of the step with the next lower component value.
+    pub prev_time: BenchTime,
+    pub curr_time: BenchTime,
 }
 
+impl<'a> MonotonicityViolation<'a> {
+    /// Decrease of the extrinsic time, in nanoseconds.
+    fn decrease(&self) -> f64 {
+        self.prev_time.to_ns() - self.curr_time.to_ns()
+    }
+}
+
+/// The averaged steps of an extrinsic within one value range of a component.
+#[derive(Debug, PartialEq)]
+struct StepRange<'a> {
+    pallet: &'a str,
+    extrinsic: &'a str,
+    /// Position of the component in the input variables.
+    component: usize,
+    lower: f64,
+    upper: f64,
+    step_count: usize,
+    avg_extrinsic_time: BenchTime,
+    avg_storage_root_time: BenchTime,
+}
+
+/// Minimum amount of steps below which `StepIncrTable::print` adds a warning.
+pub const DEFAULT_MIN_STEPS: usize = 5;
+/// Minimum amount of repeats below which `StepIncrTable::print` adds a warning.
+pub const DEFAULT_MIN_REPEATS: usize = 5;
+
 impl<'a> StepIncrTable<'a> {
+    /// # Example
+    /// ```
+    /// # use libreview::tables::StepIncrTable;
+    /// let table = StepIncrTable::new();
+    /// assert!(table.raw_list().is_empty());
+    /// ```
     pub fn new() -> Self {
         StepIncrTable {
             entries: Vec::new(),
         }
     }
-    pub(crate) fn push(&mut self, entry: StepIncrTableEntry<'a>) {
+    /// Inserts the entry if it passes `StepIncrTableEntry::validate`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTable, StepIncrTableEntry};
+    /// let (one, two) = (vec![1], vec![1, 2]);
+    /// # let step = |input_vars| StepIncr {
+    /// #     input_vars,
+    /// #     avg_extrinsic_time: 100.0.into(),
+    /// #     avg_storage_root_time: 100.0.into(),
+    /// #     ratio: 1.0,
+    /// #     extrinsic_incr_percentage: 0.0,
+    /// #     storage_root_incr_percentage: 0.0,
+    /// # };
+    /// let mut table = StepIncrTable::new();
+    /// let entry = StepIncrTableEntry {
+    ///     pallet: "democracy",
+    ///     extrinsic: "propose",
+    ///     step_incrs: vec![step(&one), step(&two)],
+    ///     ..Default::default()
+    /// };
+    /// assert!(table.try_push(entry).is_err());
+    /// assert!(table.raw_list().is_empty());
+    /// ```
+    pub fn try_push(&mut self, entry: StepIncrTableEntry<'a>) -> Result<(), ValidationError> {
+        entry.validate()?;
         self.entries.push(entry);
+        Ok(())
+    }
+    /// Creates a table of the given entries, inserting them in order with
+    /// `try_push`. Fails on the first entry which does not pass
+    /// `StepIncrTableEntry::validate`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{StepIncr, StepIncrTable, StepIncrTableEntry};
+    /// let one = vec![1];
+    /// # let entry = |extrinsic| StepIncrTableEntry {
+    /// #     pallet: "democracy",
+    /// #     extrinsic,
+    /// #     step_incrs: vec![StepIncr {
+    /// #         input_vars: &one,
+    /// #         avg_extrinsic_time: 100.0.into(),
+    /// #         avg_storage_root_time: 100.0.into(),
+    /// #         ratio: 1.0,
+    /// #         extrinsic_incr_percentage: 0.0,
+    /// #         storage_root_incr_percentage: 0.0,
+    /// #     }],
+    /// #     ..Default::default()
+    /// # };
+    /// let table = StepIncrTable::from_entries(vec![entry("propose"), entry("second")]).unwrap();
+    /// assert_eq!(table.entries().len(), 2);
+    /// ```
+    pub fn from_entries(
+        entries: impl IntoIterator<Item = StepIncrTableEntry<'a>>,
+    ) -> Result<Self, ValidationError> {
+        let mut table = StepIncrTable::new();
+        for entry in entries {
+            table.try_push(entry)?;
+        }
+
+        Ok(table)
     }
+    /// Sorts the steps of each extrinsic by the extrinsic time increase, highest
+    /// first. The extrinsics themselves are sorted by pallet name. Both sorts are
+    /// stable, so extrinsics of the same pallet keep their relative order.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_by_extrinsic_incr_percentage();
+    ///
+    /// let percentages: Vec<f64> = table.raw_list().iter().map(|e| e.6).collect();
+    /// assert_eq!(percentages, vec![15.6470, 2.6622, 0.0, 7.9605, 3.8495, 0.0]);
+    /// ```
     pub fn sort_by_extrinsic_incr_percentage(&mut self) {
+        self.sort_by_extrinsic_incr_percentage_with_order(SortOrder::Descending);
+    }
+    /// Like `sort_by_extrinsic_incr_percentage`, but sorts the steps in the
+    /// given `order`. The sorts are stable. Steps with a NaN or infinite
+    /// percentage are always moved to the end.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::tables::SortOrder;
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_by_extrinsic_incr_percentage_with_order(SortOrder::Ascending);
+    ///
+    /// let percentages: Vec<f64> = table.raw_list().iter().map(|e| e.6).collect();
+    /// assert_eq!(percentages, vec![0.0, 2.6622, 15.6470, 0.0, 3.8495, 7.9605]);
+    /// ```
+    pub fn sort_by_extrinsic_incr_percentage_with_order(&mut self, order: SortOrder) {
+        warn_non_finite(
+            "extrinsic_incr_percentage",
+            self.entries
+                .iter()
+                .flat_map(|e| e.step_incrs.iter())
+                .map(|s| s.extrinsic_incr_percentage),
+        );
+
         // Sort by increase percentages for each extrinsic
         for entry in &mut self.entries {
-            entry.step_incrs.sort_by(|a, b| {
-                b.extrinsic_incr_percentage
-                    .partial_cmp(&a.extrinsic_incr_percentage)
-                    .unwrap_or(Ordering::Equal)
-            });
+            entry.step_incrs.sort_by(|a, b| {
+                order.compare(a.extrinsic_incr_percentage, b.extrinsic_incr_percentage)
+            });
+        }
+
+        // Additionally, sort by pallet name
+        self.entries.sort_by(|a, b| a.pallet.cmp(b.pallet));
+    }
+    /// Sorts the extrinsics by pallet name, then by extrinsic name. The steps of
+    /// each extrinsic keep their order.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_entries_by_extrinsic_name();
+    ///
+    /// let names: Vec<&str> = table.raw_list().iter().map(|e| e.1).collect();
+    /// assert_eq!(names[0], "set_balance_killing");
+    /// assert_eq!(names[5], "propose");
+    /// ```
+    pub fn sort_entries_by_extrinsic_name(&mut self) {
+        self.entries
+            .sort_by(|a, b| (a.pallet, a.extrinsic).cmp(&(b.pallet, b.extrinsic)));
+    }
+    /// Sorts the extrinsics by name and the steps of each extrinsic by their
+    /// input variables. Recommended before writing the table to disk.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_by_extrinsic_name();
+    ///
+    /// let steps: Vec<&[u64]> = table.raw_list().iter().map(|e| e.2).collect();
+    /// assert_eq!(steps[..3], [[1, 1000], [199, 1000], [496, 1000]]);
+    /// assert_eq!(steps[3..], [[1], [10], [19]]);
+    /// ```
+    pub fn sort_by_extrinsic_name(&mut self) {
+        for entry in &mut self.entries {
+            entry
+                .step_incrs
+                .sort_by(|a, b| a.input_vars.cmp(b.input_vars));
+        }
+
+        self.sort_entries_by_extrinsic_name();
+    }
+    /// Sorts the extrinsics by the highest extrinsic time increase among their
+    /// steps, highest first. The steps of each extrinsic keep their order. The
+    /// sort is stable and extrinsics without steps are moved to the end.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_entries_by_max_extrinsic_percentage();
+    ///
+    /// let names: Vec<&str> = table.raw_list().iter().map(|e| e.1).collect();
+    /// assert_eq!(names[0], "set_balance_killing");
+    /// assert_eq!(names[5], "propose");
+    /// ```
+    pub fn sort_entries_by_max_extrinsic_percentage(&mut self) {
+        self.entries.sort_by(|a, b| {
+            SortOrder::Descending.compare(
+                a.max_extrinsic_incr_percentage().unwrap_or(f64::NAN),
+                b.max_extrinsic_incr_percentage().unwrap_or(f64::NAN),
+            )
+        });
+    }
+    /// Maps each extrinsic to the lowest and highest value of each of its
+    /// components (see `StepIncrTableEntry::component_value_range`), in the
+    /// order of the input variables.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    ///
+    /// let ranges = table.component_value_ranges();
+    /// assert_eq!(ranges[&("democracy", "propose")], vec![(1, 19)]);
+    /// assert_eq!(ranges[&("balances", "set_balance_killing")], vec![(1, 496), (1000, 1000)]);
+    /// ```
+    pub fn component_value_ranges(&self) -> HashMap<(&'a str, &'a str), Vec<(u64, u64)>> {
+        self.entries
+            .iter()
+            .map(|e| {
+                let component_count = e.step_incrs.first().map_or(0, |s| s.input_vars.len());
+                let ranges = (0..component_count)
+                    .filter_map(|index| e.component_value_range(index))
+                    .collect();
+
+                ((e.pallet, e.extrinsic), ranges)
+            })
+            .collect()
+    }
+    /// Returns the most sensitive component of the `top_n` extrinsics with the
+    /// highest correlation, see `StepIncrTableEntry::component_sensitivity_rank`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    ///
+    /// let top = table.top_sensitive_components(1);
+    /// assert_eq!(top.len(), 1);
+    /// assert_eq!((top[0].0, top[0].1), (("democracy", "propose"), 0));
+    /// assert!(top[0].2 > 0.9);
+    /// ```
+    pub fn top_sensitive_components(&self, top_n: usize) -> Vec<((&'a str, &'a str), usize, f64)> {
+        let mut top: Vec<((&'a str, &'a str), usize, f64)> = self
+            .entries
+            .iter()
+            .filter_map(|e| {
+                e.component_sensitivity_rank()
+                    .first()
+                    .map(|&(index, r)| ((e.pallet, e.extrinsic), index, r))
+            })
+            .collect();
+
+        top.sort_by(|a, b| SortOrder::Descending.compare(a.2, b.2));
+        top.truncate(top_n);
+        top
+    }
+    /// Pairs each extrinsic with the highest extrinsic time increase among its
+    /// steps, highest first. Extrinsics without steps are omitted. The sort is
+    /// stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    ///
+    /// let ranking = table.rank_by_max_growth();
+    /// assert_eq!(ranking.len(), 2);
+    /// assert_eq!(ranking[0].0.extrinsic, "set_balance_killing");
+    /// assert_eq!(ranking[0].1, 15.647);
+    /// ```
+    pub fn rank_by_max_growth(&self) -> Vec<(&StepIncrTableEntry<'a>, f64)> {
+        self.rank_by(StepIncrTableEntry::max_extrinsic_incr_percentage)
+    }
+    /// Like `rank_by_max_growth`, but by the highest storage root time increase.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    ///
+    /// let ranking = table.rank_by_max_storage_growth();
+    /// assert_eq!(ranking[0].0.extrinsic, "propose");
+    /// assert_eq!(ranking[0].1, 27.1982);
+    /// ```
+    pub fn rank_by_max_storage_growth(&self) -> Vec<(&StepIncrTableEntry<'a>, f64)> {
+        self.rank_by(StepIncrTableEntry::max_storage_root_incr_percentage)
+    }
+    fn rank_by(
+        &self,
+        value: fn(&StepIncrTableEntry<'a>) -> Option<f64>,
+    ) -> Vec<(&StepIncrTableEntry<'a>, f64)> {
+        let mut ranking: Vec<(&StepIncrTableEntry<'a>, f64)> = self
+            .entries
+            .iter()
+            .filter_map(|e| value(e).map(|v| (e, v)))
+            .collect();
+
+        ranking.sort_by(|a, b| SortOrder::Descending.compare(a.1, b.1));
+        ranking
+    }
+    /// Returns the pallet and extrinsic names of all extrinsics whose highest
+    /// extrinsic time increase across all steps is below `threshold` (in
+    /// percent). Extrinsics without steps are considered flat.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// assert_eq!(table.flat_extrinsics(10.0), vec![("democracy", "propose")]);
+    /// ```
+    pub fn flat_extrinsics(&self, threshold: f64) -> Vec<(&'a str, &'a str)> {
+        self.entries
+            .iter()
+            .filter(|entry| Self::is_flat(entry, threshold))
+            .map(|entry| (entry.pallet, entry.extrinsic))
+            .collect()
+    }
+    /// Removes all extrinsics listed by `flat_extrinsics`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.remove_flat_extrinsics(10.0);
+    ///
+    /// let list = table.raw_list();
+    /// assert_eq!(list.len(), 3);
+    /// assert!(list.iter().all(|e| e.1 == "set_balance_killing"));
+    /// ```
+    pub fn remove_flat_extrinsics(&mut self, max_percentage_threshold: f64) {
+        self.entries
+            .retain(|entry| !Self::is_flat(entry, max_percentage_threshold));
+    }
+    fn is_flat(entry: &StepIncrTableEntry, threshold: f64) -> bool {
+        entry
+            .max_extrinsic_incr_percentage()
+            .is_none_or(|max| max < threshold)
+    }
+    /// Creates an overview table from the worst-case step of each extrinsic,
+    /// i.e. the step with the highest extrinsic time increase. Just like the
+    /// regular overview table, the ratio and percentage of each entry are
+    /// relative to the fastest entry.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// let mut worst_case = table.to_worst_case_ratio_table();
+    /// worst_case.sort_by_ratio();
+    ///
+    /// let list = worst_case.raw_list();
+    /// assert_eq!(list[0], ("balances", "set_balance_killing", 122488.6667, 79915.0, 1.0, 0.0));
+    /// assert_eq!(list[1].4, 1.1097);
+    /// ```
+    pub fn to_worst_case_ratio_table(&self) -> PerExtrTable<'a> {
+        let mut table = PerExtrTable::new();
+        for entry in &self.entries {
+            // The first step when sorted by descending percentage.
+            let worst = entry.step_incrs.iter().min_by(|a, b| {
+                SortOrder::Descending
+                    .compare(a.extrinsic_incr_percentage, b.extrinsic_incr_percentage)
+            });
+
+            if let Some(worst) = worst {
+                table.entries.push(PerExtrTableEntry {
+                    pallet: entry.pallet,
+                    extrinsic: entry.extrinsic,
+                    avg_extrinsic_time: worst.avg_extrinsic_time,
+                    avg_storage_root_time: worst.avg_storage_root_time,
+                    ratio: 0.0,
+                    percentage: 0.0,
+                });
+            }
+        }
+
+        table.recalculate_ratios();
+        table
+    }
+    /// Creates an overview table from the step of each extrinsic whose input
+    /// variables are closest to `components`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    ///
+    /// // Only `democracy::propose` has a single component, with a step at 10
+    /// let at_step = table.into_ratio_table_at_components(&[12]).unwrap();
+    /// assert_eq!(at_step.raw_list(), vec![("democracy", "propose", 130751.0, 89704.8, 1.0, 0.0)]);
+    ///
+    /// assert!(table.into_ratio_table_at_components(&[1, 2, 3]).is_err());
+    /// ```
+    pub fn into_ratio_table_at_components(
+        &self,
+        components: &[u64],
+    ) -> Result<PerExtrTable<'a>, Error> {
+        let distance = |step: &StepIncr| {
+            step.input_vars
+                .iter()
+                .zip(components)
+                .map(|(a, b)| a.abs_diff(*b))
+                .max()
+                .unwrap_or(0)
+        };
+
+        let mut table = PerExtrTable::new();
+        for entry in &self.entries {
+            let closest = entry
+                .step_incrs
+                .iter()
+                .filter(|s| s.input_vars.len() == components.len())
+                .min_by_key(|s| distance(s));
+
+            if let Some(closest) = closest {
+                table.entries.push(PerExtrTableEntry {
+                    pallet: entry.pallet,
+                    extrinsic: entry.extrinsic,
+                    avg_extrinsic_time: closest.avg_extrinsic_time,
+                    avg_storage_root_time: closest.avg_storage_root_time,
+                    ratio: 0.0,
+                    percentage: 0.0,
+                });
+            }
+        }
+
+        if table.entries.is_empty() {
+            return Err(TableError::NoMatchingComponents(components.len()).into());
+        }
+
+        table.recalculate_ratios();
+        Ok(table)
+    }
+    /// Subtracts the harness overhead (in nanoseconds) from the average times of
+    /// each step, clamping at zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_by_extrinsic_incr_percentage();
+    ///
+    /// table.apply_overhead_discount(1000.0, 0.0);
+    /// assert_eq!(table.raw_list()[0].3, 121488.6667);
+    /// ```
+    pub fn apply_overhead_discount(
+        &mut self,
+        extrinsic_overhead_ns: f64,
+        storage_overhead_ns: f64,
+    ) {
+        for entry in &mut self.entries {
+            for step in &mut entry.step_incrs {
+                step.avg_extrinsic_time = discount(step.avg_extrinsic_time, extrinsic_overhead_ns);
+                step.avg_storage_root_time =
+                    discount(step.avg_storage_root_time, storage_overhead_ns);
+            }
+
+            entry.recalculate_percentages();
+        }
+    }
+    /// Summarizes the step growth of each pallet.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// let summary = table.summarize_by_pallet();
+    ///
+    /// assert_eq!(summary["balances"].total_steps, 3);
+    /// assert_eq!(summary["democracy"].avg_max_extrinsic_percentage, 7.9605);
+    /// ```
+    pub fn summarize_by_pallet(&self) -> BTreeMap<&'a str, StepPalletSummary<'a>> {
+        let mut pallets: BTreeMap<&'a str, Vec<&StepIncrTableEntry<'a>>> = BTreeMap::new();
+        for entry in &self.entries {
+            pallets.entry(entry.pallet).or_default().push(entry);
         }
 
-        // Additionally, sort by pallet name
-        self.entries.sort_by(|a, b| a.pallet.cmp(b.pallet));
+        pallets
+            .into_iter()
+            .map(|(pallet, entries)| {
+                let average = |values: Vec<f64>| {
+                    if values.is_empty() {
+                        0.0
+                    } else {
+                        values.iter().sum::<f64>() / values.len() as f64
+                    }
+                };
+
+                let most_variable_extrinsic = entries
+                    .iter()
+                    .filter_map(|e| e.extrinsic_time_variation().map(|cv| (e.extrinsic, cv)))
+                    .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                    .map(|(extrinsic, _)| extrinsic)
+                    .unwrap_or(entries[0].extrinsic);
+
+                let summary = StepPalletSummary {
+                    extrinsic_count: entries.len(),
+                    total_steps: entries.iter().map(|e| e.step_incrs.len()).sum(),
+                    avg_max_extrinsic_percentage: average(
+                        entries
+                            .iter()
+                            .filter_map(|e| e.max_extrinsic_incr_percentage())
+                            .collect(),
+                    ),
+                    avg_max_storage_root_percentage: average(
+                        entries
+                            .iter()
+                            .filter_map(|e| e.max_storage_root_incr_percentage())
+                            .collect(),
+                    ),
+                    most_variable_extrinsic,
+                };
+
+                (pallet, summary)
+            })
+            .collect()
+    }
+    /// Returns the steps whose extrinsic time decreased although a component
+    /// increased, highest decrease first.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// let violations = table.validate_monotonicity();
+    ///
+    /// // `set_balance_killing` gets faster as `u` increases
+    /// assert_eq!(violations.len(), 2);
+    /// assert!(violations.iter().all(|v| v.extrinsic == "set_balance_killing"));
+    /// assert!(violations.iter().all(|v| v.component_index == 0));
+    /// assert_eq!(violations[0].prev_time.to_ns(), 122488.6667);
+    /// assert_eq!(violations[0].curr_time.to_ns(), 108735.6667);
+    /// ```
+    pub fn validate_monotonicity(&self) -> Vec<MonotonicityViolation<'a>> {
+        let mut violations: Vec<MonotonicityViolation<'a>> = self
+            .entries
+            .iter()
+            .flat_map(|e| {
+                let component_count = e.step_incrs.first().map_or(0, |s| s.input_vars.len());
+                (0..component_count).flat_map(move |index| e.monotonicity_violations(index))
+            })
+            .collect();
+
+        violations.sort_by(|a, b| SortOrder::Descending.compare(a.decrease(), b.decrease()));
+        violations
+    }
+    /// Returns a warning for each extrinsic which was benchmarked with fewer
+    /// than `min_steps` steps or fewer than `min_repeats` repeats.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// // All results were benchmarked with 10 steps and 10 repeats
+    /// assert!(table.low_sample_warnings(10, 10).is_empty());
+    /// assert_eq!(table.low_sample_warnings(20, 10).len(), 2);
+    /// ```
+    pub fn low_sample_warnings(
+        &self,
+        min_steps: usize,
+        min_repeats: usize,
+    ) -> Vec<LowSampleWarning<'a>> {
+        self.entries
+            .iter()
+            .filter(|e| e.steps < min_steps || e.repeats < min_repeats)
+            .map(|e| LowSampleWarning {
+                pallet: e.pallet,
+                extrinsic: e.extrinsic,
+                actual_steps: e.steps,
+                actual_repeats: e.repeats,
+            })
+            .collect()
+    }
+    /// The entries (extrinsics) of the table, in their current order.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_entries_by_extrinsic_name();
+    ///
+    /// let entries = table.entries();
+    /// assert_eq!(entries.len(), 2);
+    /// assert_eq!(entries[0].extrinsic, "set_balance_killing");
+    /// ```
+    pub fn entries(&self) -> &[StepIncrTableEntry<'a>] {
+        &self.entries
+    }
+    /// The pallet and extrinsic name of each entry, in the current order of the
+    /// table.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_entries_by_extrinsic_name();
+    ///
+    /// assert_eq!(
+    ///     table.extrinsic_names(),
+    ///     vec![("balances", "set_balance_killing"), ("democracy", "propose")]
+    /// );
+    /// ```
+    pub fn extrinsic_names(&self) -> Vec<(&'a str, &'a str)> {
+        self.entries
+            .iter()
+            .map(|e| (e.pallet, e.extrinsic))
+            .collect()
     }
     /// Returns a list of the entries.
     ///
@@ -154,10 +4316,11 @@ impl<'a> StepIncrTable<'a> {
     ///     ("democracy", "propose", &vec![1], 125904.3333, 84059.3333, 1.0, 0.0, 0.0),
     /// ];
     /// ```
+    #[allow(clippy::type_complexity)]
     pub fn raw_list(&self) -> Vec<(&str, &str, &[u64], f64, f64, f64, f64, f64)> {
         self.entries
             .iter()
-            .map(|e| {
+            .flat_map(|e| {
                 e.step_incrs
                     .iter()
                     .map(|s| {
@@ -165,8 +4328,8 @@ impl<'a> StepIncrTable<'a> {
                             e.pallet,
                             e.extrinsic,
                             s.input_vars.as_slice(),
-                            s.avg_extrinsic_time,
-                            s.avg_storage_root_time,
+                            s.avg_extrinsic_time.to_ns(),
+                            s.avg_storage_root_time.to_ns(),
                             s.ratio,
                             s.extrinsic_incr_percentage,
                             s.storage_root_incr_percentage,
@@ -174,10 +4337,9 @@ impl<'a> StepIncrTable<'a> {
                     })
                     .collect::<Vec<(&str, &str, &[u64], f64, f64, f64, f64, f64)>>()
             })
-            .flatten()
             .collect()
     }
-    fn build_table(&self) -> prettytable::Table {
+    fn build_table(&self, options: &PrintOptions) -> prettytable::Table {
         fn display_slice(slice: &[u64]) -> String {
             let mut s = String::new();
 
@@ -190,6 +4352,15 @@ impl<'a> StepIncrTable<'a> {
             s
         }
 
+        let formatter = &options.time_formatter;
+        let times: Vec<BenchTime> = self
+            .entries
+            .iter()
+            .flat_map(|entry| entry.step_incrs.iter())
+            .flat_map(|step| vec![step.avg_extrinsic_time, step.avg_storage_root_time])
+            .collect();
+        let unit = formatter.resolve_unit(&times);
+
         let mut table = prettytable::Table::new();
 
         // Header
@@ -198,33 +4369,902 @@ impl<'a> StepIncrTable<'a> {
             "Pallet",
             "Extrinsic",
             "Variables",
-            "Avg. Extrinsic\nTime",
-            "Avg. Storage\nRoot Time",
+            format!("Avg. Extrinsic\nTime ({})", unit.label()),
+            format!("Avg. Storage\nRoot Time ({})", unit.label()),
             "Extrinsic Time\nRatio (1:x)",
             "Extrinsic Time\nIncrease (%)",
             "Storage Root Time\nIncrease (%)"
         ]);
 
         // Body
-        for entry in self.raw_list() {
-            table.add_row(row![
-                entry.0,
-                entry.1,
-                display_slice(entry.2),
-                entry.3,
-                entry.4,
-                entry.5,
-                entry.6,
-                entry.7,
-            ]);
+        for entry in &self.entries {
+            for step in &entry.step_incrs {
+                table.add_row(row![
+                    entry.pallet,
+                    entry.extrinsic,
+                    display_slice(step.input_vars),
+                    formatter.format(step.avg_extrinsic_time, unit),
+                    formatter.format(step.avg_storage_root_time, unit),
+                    step.ratio,
+                    step.extrinsic_incr_percentage,
+                    step.storage_root_incr_percentage,
+                ]);
+            }
         }
 
         table
     }
+    /// Prints the table to stdout. Extrinsics with fewer than
+    /// `DEFAULT_MIN_STEPS` steps or `DEFAULT_MIN_REPEATS` repeats are listed
+    /// as footnotes below the table.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_by_extrinsic_incr_percentage();
+    /// table.print();
+    /// ```
     pub fn print(&self) {
-        self.build_table().printstd();
+        self.print_with_options(&PrintOptions::default());
+    }
+    /// Same as `print`, but displays the times as configured in `options`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::{PrintOptions, TimeFormatter, TimeUnit};
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// table.print_with_options(&PrintOptions {
+    ///     time_formatter: TimeFormatter {
+    ///         display_unit: TimeUnit::Auto,
+    ///         decimal_places: 2,
+    ///     },
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn print_with_options(&self, options: &PrintOptions) {
+        self.build_table(options).printstd();
+
+        for warning in self.low_sample_warnings(DEFAULT_MIN_STEPS, DEFAULT_MIN_REPEATS) {
+            println!(
+                "* Low sample size: {} {} (steps: {}, repeats: {})",
+                warning.pallet, warning.extrinsic, warning.actual_steps, warning.actual_repeats
+            );
+        }
+    }
+    /// Writes the steps of each extrinsic into `<output_dir>/<pallet>_<extrinsic>.csv`
+    /// and returns the paths of the created files. Extrinsics whose sanitized
+    /// names collide get a numeric suffix, e.g. `<pallet>_<extrinsic>_2.csv`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// let output_dir = std::env::temp_dir().join("bench-review-per-extrinsic-csv-doc");
+    ///
+    /// let paths = table.write_per_extrinsic_csv(&output_dir).unwrap();
+    /// assert_eq!(paths.len(), 2);
+    /// assert!(paths.contains(&output_dir.join("democracy_propose.csv")));
+    /// # std::fs::remove_dir_all(&output_dir).unwrap();
+    /// ```
+    pub fn write_per_extrinsic_csv(&self, output_dir: &Path) -> Result<Vec<PathBuf>, Error> {
+        fn sanitize(name: &str) -> String {
+            name.chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect()
+        }
+
+        fs::create_dir_all(output_dir)?;
+
+        let mut paths = vec![];
+        let mut used_names = HashSet::new();
+        for entry in &self.entries {
+            let name = format!("{}_{}", sanitize(entry.pallet), sanitize(entry.extrinsic));
+            let mut file_name = format!("{}.csv", name);
+            let mut suffix = 1;
+            while !used_names.insert(file_name.clone()) {
+                suffix += 1;
+                file_name = format!("{}_{}.csv", name, suffix);
+            }
+            if suffix > 1 {
+                warn!(
+                    "{}::{} is written to {}, since {}.csv is already taken",
+                    entry.pallet, entry.extrinsic, file_name, name
+                );
+            }
+
+            let path = output_dir.join(file_name);
+            let mut writer = csv::Writer::from_path(&path)?;
+
+            // All steps have the same amount of input variables, see `validate`.
+            let component_count = entry
+                .step_incrs
+                .first()
+                .map_or(entry.input_var_names.len(), |s| s.input_vars.len());
+            let mut header: Vec<String> = (0..component_count)
+                .map(|i| {
+                    entry
+                        .input_var_names
+                        .get(i)
+                        .cloned()
+                        .unwrap_or_else(|| format!("component_{}", i))
+                })
+                .collect();
+            header.extend(
+                [
+                    "avg_extrinsic_time",
+                    "avg_storage_root_time",
+                    "ratio",
+                    "extrinsic_incr_percentage",
+                    "storage_root_incr_percentage",
+                ]
+                .iter()
+                .map(|s| s.to_string()),
+            );
+            writer.write_record(&header)?;
+
+            let mut steps: Vec<&StepIncr> = entry.step_incrs.iter().collect();
+            steps.sort_by(|a, b| a.input_vars.cmp(b.input_vars));
+
+            for step in steps {
+                let mut record: Vec<String> =
+                    step.input_vars.iter().map(|v| v.to_string()).collect();
+                record.extend(
+                    [
+                        step.avg_extrinsic_time.to_ns(),
+                        step.avg_storage_root_time.to_ns(),
+                        step.ratio,
+                        step.extrinsic_incr_percentage,
+                        step.storage_root_incr_percentage,
+                    ]
+                    .iter()
+                    .map(|v| v.to_string()),
+                );
+                writer.write_record(&record)?;
+            }
+
+            writer.flush()?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+    /// Averages the steps of each extrinsic per component value range. The
+    /// range of each component is divided into `bucket_count` equally-spaced
+    /// buckets, empty buckets are skipped.
+    fn step_ranges(&self, bucket_count: usize) -> Vec<StepRange<'a>> {
+        let bucket_count = bucket_count.max(1);
+        let mut ranges = vec![];
+
+        for entry in &self.entries {
+            let component_count = entry
+                .step_incrs
+                .iter()
+                .map(|s| s.input_vars.len())
+                .min()
+                .unwrap_or(0);
+
+            for component in 0..component_count {
+                let values = entry.step_incrs.iter().map(|s| s.input_vars[component]);
+                // Unwrapping is ok, since there is at least one step
+                let min = values.clone().min().unwrap() as f64;
+                let max = values.max().unwrap() as f64;
+                let width = (max - min) / bucket_count as f64;
+
+                let mut buckets: Vec<Vec<&StepIncr>> = vec![vec![]; bucket_count];
+                for step in &entry.step_incrs {
+                    let index = if width > 0.0 {
+                        ((step.input_vars[component] as f64 - min) / width) as usize
+                    } else {
+                        0
+                    };
+                    buckets[index.min(bucket_count - 1)].push(step);
+                }
+
+                for (index, steps) in buckets.iter().enumerate() {
+                    if steps.is_empty() {
+                        continue;
+                    }
+
+                    let count = steps.len() as f64;
+                    let mean = |time: fn(&StepIncr) -> BenchTime| -> BenchTime {
+                        (steps.iter().map(|s| time(s).to_ns()).sum::<f64>() / count)
+                            .round_by(4)
+                            .into()
+                    };
+
+                    ranges.push(StepRange {
+                        pallet: entry.pallet,
+                        extrinsic: entry.extrinsic,
+                        component,
+                        lower: (min + index as f64 * width).round_by(4),
+                        upper: (min + (index + 1) as f64 * width).round_by(4),
+                        step_count: steps.len(),
+                        avg_extrinsic_time: mean(|s| s.avg_extrinsic_time),
+                        avg_storage_root_time: mean(|s| s.avg_storage_root_time),
+                    });
+                }
+            }
+        }
+
+        ranges
+    }
+    /// Prints the average times of each extrinsic per component value range
+    /// instead of per step.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// table.print_step_ranges(4, &Default::default());
+    /// ```
+    pub fn print_step_ranges(&self, bucket_count: usize, options: &PrintOptions) {
+        let ranges = self.step_ranges(bucket_count);
+        let formatter = &options.time_formatter;
+        let times: Vec<BenchTime> = ranges
+            .iter()
+            .flat_map(|range| vec![range.avg_extrinsic_time, range.avg_storage_root_time])
+            .collect();
+        let unit = formatter.resolve_unit(&times);
+
+        let mut table = prettytable::Table::new();
+
+        // Header
+        table.add_row(row![
+            bc =>
+            "Pallet",
+            "Extrinsic",
+            "Component",
+            "Range",
+            "Steps",
+            format!("Avg. Extrinsic\nTime ({})", unit.label()),
+            format!("Avg. Storage\nRoot Time ({})", unit.label())
+        ]);
+
+        // Body
+        for range in ranges {
+            table.add_row(row![
+                range.pallet,
+                range.extrinsic,
+                range.component,
+                format!("{} - {}", range.lower, range.upper),
+                range.step_count,
+                formatter.format(range.avg_extrinsic_time, unit),
+                formatter.format(range.avg_storage_root_time, unit),
+            ]);
+        }
+
+        table.printstd();
     }
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut table = collection.generate_step_table().unwrap();
+    /// table.sort_by_extrinsic_incr_percentage();
+    /// table.print_csv();
+    /// ```
     pub fn print_csv(&self) {
-        self.build_table().to_csv(stdout()).unwrap();
+        self.print_csv_with_options(&PrintOptions::default());
+    }
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::{PrintOptions, TimeFormatter, TimeUnit};
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// table.print_csv_with_options(&PrintOptions {
+    ///     time_formatter: TimeFormatter {
+    ///         display_unit: TimeUnit::Microseconds,
+    ///         decimal_places: 3,
+    ///     },
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn print_csv_with_options(&self, options: &PrintOptions) {
+        write_table_csv(&self.build_table(options), stdout(), &CsvOptions::default()).unwrap();
+    }
+    /// Writes the table as comma separated values into `writer`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    ///
+    /// let mut csv = Vec::new();
+    /// table.write_csv(&mut csv).unwrap();
+    /// assert!(String::from_utf8(csv).unwrap().starts_with("Pallet,Extrinsic,"));
+    /// ```
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<(), Error> {
+        self.write_csv_with_options(writer, &CsvOptions::default())
+    }
+    /// Writes the table as CSV into `writer`, using the dialect of `options`.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::{CsvOptions, LineEnding};
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let table = collection.generate_step_table().unwrap();
+    /// let options = CsvOptions {
+    ///     delimiter: ';',
+    ///     always_quote: true,
+    ///     line_ending: LineEnding::Windows,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut csv = Vec::new();
+    /// table.write_csv_with_options(&mut csv, &options).unwrap();
+    /// assert!(String::from_utf8(csv).unwrap().starts_with("\"Pallet\";\"Extrinsic\";"));
+    /// ```
+    pub fn write_csv_with_options<W: Write>(
+        &self,
+        writer: W,
+        options: &CsvOptions,
+    ) -> Result<(), Error> {
+        write_table_csv(&self.build_table(&PrintOptions::default()), writer, options)
+    }
+}
+
+/// Returns the first entry of the given `(pallet, extrinsic)`.
+///
+/// # Panics
+/// Panics if the table does not contain the extrinsic. Use `PerExtrTable::get`
+/// for a non-panicking lookup.
+///
+/// # Example
+/// ```
+/// # use libreview::ExtrinsicCollection;
+/// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+/// let mut table = collection.generate_ratio_table().unwrap();
+/// assert_eq!(table[("balances", "transfer")].ratio, 2.4501);
+///
+/// table[("balances", "transfer")].ratio = 2.5;
+/// assert_eq!(table[("balances", "transfer")].ratio, 2.5);
+/// ```
+impl<'a> Index<(&str, &str)> for PerExtrTable<'a> {
+    type Output = PerExtrTableEntry<'a>;
+
+    fn index(&self, (pallet, extrinsic): (&str, &str)) -> &Self::Output {
+        self.get(pallet, extrinsic)
+            .unwrap_or_else(|| panic!("no entry for {}::{}", pallet, extrinsic))
+    }
+}
+
+/// See the `Index` implementation.
+impl<'a> IndexMut<(&str, &str)> for PerExtrTable<'a> {
+    fn index_mut(&mut self, (pallet, extrinsic): (&str, &str)) -> &mut Self::Output {
+        self.get_mut(pallet, extrinsic)
+            .unwrap_or_else(|| panic!("no entry for {}::{}", pallet, extrinsic))
+    }
+}
+
+/// Overview table entries joined with the step data of the same extrinsic.
+#[derive(Debug, Default)]
+pub struct CompositeRatioTable<'a> {
+    entries: Vec<CompositeEntry<'a>>,
+}
+
+/// An overview table entry and, if available, the step table entry of the
+/// same pallet and extrinsic.
+#[derive(Debug)]
+pub struct CompositeEntry<'a> {
+    pub ratio_entry: &'a PerExtrTableEntry<'a>,
+    pub step_entry: Option<&'a StepIncrTableEntry<'a>>,
+}
+
+impl<'a> CompositeRatioTable<'a> {
+    /// Joins both tables by pallet and extrinsic name, in the order of the
+    /// overview table. If the step table contains an extrinsic multiple
+    /// times, the first occurrence is used.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::CompositeRatioTable;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let mut ratio_table = collection.generate_ratio_table().unwrap();
+    /// ratio_table.sort_by_ratio();
+    /// let step_table = collection.generate_step_table().unwrap();
+    ///
+    /// let composite = CompositeRatioTable::join(&ratio_table, &step_table);
+    /// let entry = &composite.entries()[0];
+    /// assert_eq!(entry.ratio_entry.extrinsic, "set_balance_killing");
+    /// assert_eq!(entry.step_entry.unwrap().step_incrs.len(), 3);
+    /// ```
+    pub fn join(ratio: &'a PerExtrTable<'a>, step: &'a StepIncrTable<'a>) -> Self {
+        let mut steps: HashMap<(&str, &str), &StepIncrTableEntry> = HashMap::new();
+        for entry in &step.entries {
+            steps
+                .entry((entry.pallet, entry.extrinsic))
+                .or_insert(entry);
+        }
+
+        CompositeRatioTable {
+            entries: ratio
+                .entries
+                .iter()
+                .map(|ratio_entry| CompositeEntry {
+                    ratio_entry,
+                    step_entry: steps
+                        .get(&(ratio_entry.pallet, ratio_entry.extrinsic))
+                        .copied(),
+                })
+                .collect(),
+        }
+    }
+    /// # Example
+    /// ```
+    /// # use libreview::tables::{CompositeRatioTable, PerExtrTable, StepIncrTable};
+    /// let (ratio_table, step_table) = (PerExtrTable::new(), StepIncrTable::new());
+    /// assert!(CompositeRatioTable::join(&ratio_table, &step_table).entries().is_empty());
+    /// ```
+    pub fn entries(&self) -> &[CompositeEntry<'a>] {
+        &self.entries
+    }
+    /// Prints the ratio and the step growth of each extrinsic in the same row.
+    /// Step columns are empty for extrinsics without step data.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::ExtrinsicCollection;
+    /// # use libreview::tables::CompositeRatioTable;
+    /// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+    /// let ratio_table = collection.generate_ratio_table().unwrap();
+    /// let step_table = collection.generate_step_table().unwrap();
+    /// CompositeRatioTable::join(&ratio_table, &step_table).print();
+    /// ```
+    pub fn print(&self) {
+        fn display(value: Option<f64>) -> String {
+            value.map(|v| v.to_string()).unwrap_or_default()
+        }
+
+        let mut table = prettytable::Table::new();
+
+        // Header
+        table.add_row(row![
+            bc =>
+            "Pallet",
+            "Extrinsic",
+            "Extrinsic Time\nRatio (1:x)",
+            "Extrinsic Time\nIncrease (%)",
+            "Steps",
+            "Max. Extrinsic Time\nStep Increase (%)",
+            "Max. Storage Root Time\nStep Increase (%)"
+        ]);
+
+        // Body
+        for entry in &self.entries {
+            let step = entry.step_entry;
+            table.add_row(row![
+                entry.ratio_entry.pallet,
+                entry.ratio_entry.extrinsic,
+                entry.ratio_entry.ratio,
+                entry.ratio_entry.percentage,
+                step.map(|s| s.step_incrs.len().to_string())
+                    .unwrap_or_default(),
+                display(step.and_then(|s| s.max_extrinsic_incr_percentage())),
+                display(step.and_then(|s| s.max_storage_root_incr_percentage())),
+            ]);
+        }
+
+        table.printstd();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(extrinsic: &str, ratio: f64) -> PerExtrTableEntry<'_> {
+        PerExtrTableEntry {
+            pallet: "balances",
+            extrinsic,
+            avg_extrinsic_time: 100.0.into(),
+            avg_storage_root_time: 100.0.into(),
+            ratio,
+            percentage: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_bench_time_units() {
+        let time = BenchTime::from(1_500_000.0);
+        assert_eq!(time.to_ns(), 1_500_000.0);
+        assert_eq!(time.to_us(), 1_500.0);
+        assert_eq!(time.to_ms(), 1.5);
+        assert_eq!(f64::from(time), 1_500_000.0);
+    }
+
+    #[test]
+    fn test_time_formatter_auto_unit() {
+        let formatter = TimeFormatter {
+            display_unit: TimeUnit::Auto,
+            decimal_places: 1,
+        };
+
+        let times = [BenchTime::from(1_500_000.0), BenchTime::from(2_000_000.0)];
+        assert_eq!(formatter.resolve_unit(&times), TimeUnit::Milliseconds);
+
+        let times = [BenchTime::from(999.0), BenchTime::from(2_000_000.0)];
+        assert_eq!(formatter.resolve_unit(&times), TimeUnit::Nanoseconds);
+
+        // Zero times (e.g. after overhead discounts) don't force nanoseconds
+        let times = [BenchTime::from(0.0), BenchTime::from(2_000.0)];
+        assert_eq!(formatter.resolve_unit(&times), TimeUnit::Microseconds);
+
+        assert_eq!(formatter.resolve_unit(&[]), TimeUnit::Milliseconds);
+        assert_eq!(
+            formatter.format(BenchTime::from(1_550_000.0), TimeUnit::Milliseconds),
+            "1.6"
+        );
+    }
+    #[test]
+    fn test_write_csv_with_options() {
+        let mut table = PerExtrTable::new();
+        table.push(entry("transfer", 1.0)).unwrap();
+
+        let options = CsvOptions {
+            delimiter: ';',
+            quote_char: '\'',
+            always_quote: true,
+            line_ending: LineEnding::Windows,
+        };
+        let mut csv = Vec::new();
+        table.write_csv_with_options(&mut csv, &options).unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        let rows: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[1],
+            "'balances';'transfer';'100.0000';'100.0000';'1';'0'"
+        );
+        assert!(rows[2].is_empty());
+
+        let mut csv = Vec::new();
+        table.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert!(csv.ends_with("\nbalances,transfer,100.0000,100.0000,1,0\n"));
+
+        let options = CsvOptions {
+            delimiter: '§',
+            ..Default::default()
+        };
+        assert!(table.write_csv_with_options(Vec::new(), &options).is_err());
+    }
+    #[test]
+    fn test_group_by_pallet() {
+        let mut table = PerExtrTable::new();
+        table.push(entry("transfer", 1.0)).unwrap();
+        table
+            .push(PerExtrTableEntry {
+                pallet: "staking",
+                ..entry("bond", 2.0)
+            })
+            .unwrap();
+        table.push(entry("set_balance", 3.0)).unwrap();
+
+        let groups = table.group_by_pallet();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "balances");
+        assert_eq!(groups[0].1[1].extrinsic, "set_balance");
+
+        let options = PrintOptions {
+            group_by_pallet: true,
+            ..Default::default()
+        };
+        let mut csv = Vec::new();
+        write_table_csv(
+            &table.build_table(&options),
+            &mut csv,
+            &CsvOptions::default(),
+        )
+        .unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        // Skip the multi-line header
+        let body = csv.rsplit("Increase (%)\"\n").next().unwrap();
+        let rows: Vec<&str> = body.lines().collect();
+        assert_eq!(rows[0], "BALANCES");
+        assert_eq!(rows[3], "Average,2 extrinsic(s),100.0000,100.0000,2,0");
+        assert_eq!(rows[4], "STAKING");
+        assert_eq!(rows.len(), 7);
+    }
+    #[test]
+    fn test_step_ranges() {
+        let (collection, _) =
+            crate::ExtrinsicCollection::from_directory("tests/files/single_pallet/").unwrap();
+        let mut table = collection.generate_step_table().unwrap();
+        table.entries.retain(|entry| entry.extrinsic == "transfer");
+
+        let ranges = table.step_ranges(2);
+        assert_eq!(ranges.len(), 2);
+        assert_eq!((ranges[0].lower, ranges[0].upper), (1.0, 50.5));
+        assert_eq!(ranges[0].step_count, 2);
+        assert_eq!(ranges[0].avg_extrinsic_time.to_ns(), 186000.0);
+        assert_eq!(ranges[0].avg_storage_root_time.to_ns(), 82200.0);
+        assert_eq!((ranges[1].lower, ranges[1].upper), (50.5, 100.0));
+        assert_eq!(ranges[1].step_count, 1);
+        assert_eq!(ranges[1].avg_extrinsic_time.to_ns(), 201000.0);
+
+        // Empty buckets are skipped, zero buckets behave like a single one
+        assert_eq!(table.step_ranges(10).len(), 3);
+        assert_eq!(table.step_ranges(0).len(), 1);
+    }
+    #[test]
+    fn test_monotonicity_violations_per_series() {
+        let vars = [vec![1, 1], vec![1, 2], vec![2, 1], vec![2, 2]];
+        let step = |input_vars, avg_extrinsic_time: f64| StepIncr {
+            input_vars,
+            avg_extrinsic_time: avg_extrinsic_time.into(),
+            avg_storage_root_time: 100.0.into(),
+            ratio: 1.0,
+            extrinsic_incr_percentage: 0.0,
+            storage_root_incr_percentage: 0.0,
+        };
+        let entry = StepIncrTableEntry {
+            pallet: "democracy",
+            extrinsic: "propose",
+            step_incrs: vec![
+                step(&vars[0], 100.0),
+                step(&vars[1], 300.0),
+                step(&vars[2], 200.0),
+                step(&vars[3], 250.0),
+            ],
+            ..Default::default()
+        };
+
+        // Only steps differing in the first component are compared
+        let violations = entry.monotonicity_violations(0);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].violation_step_index, 3);
+        assert_eq!(violations[0].decrease(), 50.0);
+
+        assert!(entry.monotonicity_violations(1).is_empty());
+        assert!(entry.monotonicity_violations(2).is_empty());
+    }
+    #[test]
+    fn test_max_step_growth_rate_per_series() {
+        let vars = [vec![1, 1], vec![1, 5], vec![2, 1], vec![2, 5]];
+        let step = |input_vars, avg_extrinsic_time: f64| StepIncr {
+            input_vars,
+            avg_extrinsic_time: avg_extrinsic_time.into(),
+            avg_storage_root_time: 100.0.into(),
+            ratio: 1.0,
+            extrinsic_incr_percentage: 0.0,
+            storage_root_incr_percentage: 0.0,
+        };
+        let entry = StepIncrTableEntry {
+            pallet: "democracy",
+            extrinsic: "propose",
+            step_incrs: vec![
+                step(&vars[0], 100.0),
+                step(&vars[1], 1000.0),
+                step(&vars[2], 200.0),
+                step(&vars[3], 1500.0),
+            ],
+            ..Default::default()
+        };
+
+        // Only steps differing in the given component are compared
+        assert_eq!(entry.max_step_growth_rate(0), Some(500.0));
+        assert_eq!(entry.max_step_growth_rate(1), Some(325.0));
+        assert_eq!(entry.max_step_growth_rate(2), None);
+    }
+    #[test]
+    fn test_ratio_statistics_ignore_non_finite() {
+        let mut table = PerExtrTable::new();
+        assert_eq!(table.mean_ratio(), None);
+        assert_eq!(table.variance(), None);
+        assert_eq!(table.median_ratio(), None);
+
+        table.entries.push(entry("transfer", 1.0));
+        table.entries.push(entry("set_balance", f64::NAN));
+        table.entries.push(entry("transfer_keep_alive", 3.0));
+        assert_eq!(table.mean_ratio(), Some(2.0));
+        assert_eq!(table.variance(), Some(1.0));
+        assert_eq!(table.median_ratio(), Some(2.0));
+    }
+    #[test]
+    fn test_clear_keeps_capacity() {
+        let mut table = PerExtrTable::new();
+        table.push(entry("transfer", 1.0)).unwrap();
+        table.push(entry("set_balance", 2.0)).unwrap();
+
+        table.clear();
+        assert!(table.entries.is_empty());
+        assert!(table.entries.capacity() >= 2);
+
+        table.clear_and_shrink();
+        assert_eq!(table.entries.capacity(), 0);
+    }
+    #[test]
+    fn test_pearson_correlation() {
+        assert_eq!(
+            pearson_correlation(&[(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)]),
+            Some(1.0)
+        );
+        assert_eq!(
+            pearson_correlation(&[(1.0, 6.0), (2.0, 4.0), (3.0, 2.0)]),
+            Some(-1.0)
+        );
+        assert_eq!(pearson_correlation(&[(1.0, 2.0), (1.0, 4.0)]), None);
+        assert_eq!(pearson_correlation(&[]), None);
+    }
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(escape_html("transfer"), "transfer");
+        assert_eq!(
+            escape_html("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+    #[test]
+    #[should_panic(expected = "no entry for balances::set_balance")]
+    fn test_index_panics_on_missing_entry() {
+        let mut table = PerExtrTable::new();
+        table.push(entry("transfer", 1.0)).unwrap();
+
+        let _ = &table[("balances", "set_balance")];
+    }
+    #[test]
+    fn test_discount_clamps_at_zero() {
+        assert_eq!(discount(100.0.into(), 40.0), BenchTime::from(60.0));
+        assert_eq!(discount(100.0.into(), 140.0), BenchTime::from(0.0));
+    }
+
+    #[test]
+    fn test_owned_entries_ext() {
+        let mut table = PerExtrTable::new();
+        table.push(entry("transfer", 3.0)).unwrap();
+        table.push(entry("set_balance", 1.0)).unwrap();
+        let mut other = entry("bond", 2.0);
+        other.pallet = "staking";
+        table.push(other).unwrap();
+
+        let mut owned = table.to_owned_entries();
+        drop(table);
+
+        assert_eq!(owned.mean_ratio(), 2.0);
+        assert_eq!(owned.filter_by_pallet("balances").len(), 2);
+        assert!(owned.filter_by_pallet("identity").is_empty());
+
+        owned.sort_by_ratio();
+        let order: Vec<&str> = owned.iter().map(|e| e.extrinsic.as_str()).collect();
+        assert_eq!(order, vec!["set_balance", "bond", "transfer"]);
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut table = PerExtrTable::new();
+        assert!(table.summary().is_none());
+
+        for (extrinsic, ratio) in &[("a", 1.0), ("b", 2.0), ("c", 3.0), ("d", 6.0)] {
+            table.push(entry(extrinsic, *ratio)).unwrap();
+        }
+
+        let summary = table.summary().unwrap();
+        assert_eq!(summary.count, 4);
+        assert_eq!(summary.mean_ratio, 3.0);
+        assert_eq!(summary.std_dev_ratio, 1.8708);
+        assert_eq!(summary.min_ratio, 1.0);
+        assert_eq!(summary.max_ratio, 6.0);
+        assert_eq!(summary.median_ratio, 2.5);
+        assert_eq!(summary.p90_ratio, 6.0);
+
+        let mut frequencies = HashMap::new();
+        frequencies.insert(("balances".to_string(), "d".to_string()), 4.0);
+        frequencies.insert(("balances".to_string(), "c".to_string()), 0.0);
+
+        // Ratios 1.0, 2.0 and 6.0, weighted 1, 1 and 4
+        let summary = table.frequency_weighted_summary(&frequencies).unwrap();
+        assert_eq!(summary.count, 3);
+        assert_eq!(summary.mean_ratio, 4.5);
+        assert_eq!(summary.median_ratio, 4.4);
+        assert_eq!(summary.min_ratio, 1.0);
+    }
+
+    #[test]
+    fn test_push_rejects_non_finite_ratio() {
+        let mut table = PerExtrTable::new();
+        assert!(table.push(entry("transfer", 1.0)).is_ok());
+        assert!(table.push(entry("transfer", f64::NAN)).is_err());
+        assert!(table.push(entry("transfer", f64::INFINITY)).is_err());
+        assert_eq!(table.raw_list().len(), 1);
+    }
+
+    #[test]
+    fn test_validate_entry() {
+        assert!(PerExtrTable::validate_entry(&entry("transfer", 1.0)).is_ok());
+
+        let mut invalid = entry("transfer", 1.0);
+        invalid.pallet = "";
+        match PerExtrTable::validate_entry(&invalid) {
+            Err(Empty { field }) => assert_eq!(field, "pallet"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        match PerExtrTable::validate_entry(&entry("", 1.0)) {
+            Err(Empty { field }) => assert_eq!(field, "extrinsic"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut invalid = entry("transfer", 1.0);
+        invalid.avg_extrinsic_time = (-1.0).into();
+        match PerExtrTable::validate_entry(&invalid) {
+            Err(Negative { field, value }) => {
+                assert_eq!(field, "avg_extrinsic_time");
+                assert_eq!(value, -1.0);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut invalid = entry("transfer", 1.0);
+        invalid.avg_storage_root_time = (-0.5).into();
+        match PerExtrTable::validate_entry(&invalid) {
+            Err(Negative { field, value }) => {
+                assert_eq!(field, "avg_storage_root_time");
+                assert_eq!(value, -0.5);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        match PerExtrTable::validate_entry(&entry("transfer", f64::NAN)) {
+            Err(NonFinite { field, .. }) => assert_eq!(field, "ratio"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut invalid = entry("transfer", 1.0);
+        invalid.percentage = f64::NAN;
+        match PerExtrTable::validate_entry(&invalid) {
+            Err(NonFinite { field, .. }) => assert_eq!(field, "percentage"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_json_round_trip() {
+        let mut table = PerExtrTable::new();
+        table.push(entry("transfer", 1.0)).unwrap();
+        table.push(entry("set_balance", 2.5)).unwrap();
+
+        let json = table.to_json_string().unwrap();
+        let parsed = PerExtrTable::from_json_str(&json).unwrap();
+        assert_eq!(parsed.raw_list(), table.raw_list());
+
+        let json = table.to_json_pretty().unwrap();
+        let parsed = PerExtrTable::from_json_str(&json).unwrap();
+        assert_eq!(parsed.raw_list(), table.raw_list());
+    }
+
+    #[test]
+    fn test_sort_moves_non_finite_to_end() {
+        let mut table = PerExtrTable::new();
+        table.push(entry("a", 2.0)).unwrap();
+        table.push(entry("b", 1.0)).unwrap();
+        table.push(entry("c", 3.0)).unwrap();
+        table.entry_at_mut(1).unwrap().ratio = f64::NAN;
+
+        table.sort_by_ratio();
+        let order: Vec<&str> = table.raw_list().iter().map(|e| e.1).collect();
+        assert_eq!(order, vec!["a", "c", "b"]);
+
+        table.sort_by_ratio_desc();
+        let order: Vec<&str> = table.raw_list().iter().map(|e| e.1).collect();
+        assert_eq!(order, vec!["c", "a", "b"]);
     }
 }
@@ -0,0 +1,71 @@
+//! Assertions for regression guards in test suites, e.g. of pallets which
+//! check their benchmark results. Requires the `testing` feature.
+
+use super::tables::PerExtrTable;
+
+/// Panics if the ratio of `pallet::extrinsic` exceeds `max_ratio`, or if the
+/// table does not contain the extrinsic.
+///
+/// # Example
+/// ```
+/// # use libreview::testing::assert_ratio_below_threshold;
+/// # use libreview::ExtrinsicCollection;
+/// # let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+/// let table = collection.generate_ratio_table().unwrap();
+/// assert_ratio_below_threshold(&table, "balances", "transfer", 3.0);
+/// ```
+pub fn assert_ratio_below_threshold(
+    table: &PerExtrTable,
+    pallet: &str,
+    extrinsic: &str,
+    max_ratio: f64,
+) {
+    let entry = match table.get(pallet, extrinsic) {
+        Some(entry) => entry,
+        None => panic!(
+            "no benchmark result for {}::{} among the {} entries of the table",
+            pallet,
+            extrinsic,
+            table.entries().len()
+        ),
+    };
+
+    // NaN ratios fail too
+    if entry.ratio.is_nan() || entry.ratio > max_ratio {
+        panic!(
+            "ratio of {}::{} exceeds the threshold: {} > {} (avg. extrinsic time {} ns, \
+             avg. storage root time {} ns)",
+            pallet,
+            extrinsic,
+            entry.ratio,
+            max_ratio,
+            entry.avg_extrinsic_time.to_ns(),
+            entry.avg_storage_root_time.to_ns()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExtrinsicCollection;
+
+    #[test]
+    #[should_panic(expected = "ratio of democracy::delegate exceeds the threshold: 19.6006 > 10")]
+    fn test_assert_ratio_above_threshold() {
+        let (collection, _) =
+            ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+        let table = collection.generate_ratio_table().unwrap();
+
+        assert_ratio_below_threshold(&table, "democracy", "delegate", 10.0);
+    }
+    #[test]
+    #[should_panic(expected = "no benchmark result for balances::set_balance")]
+    fn test_assert_ratio_of_missing_extrinsic() {
+        let (collection, _) =
+            ExtrinsicCollection::from_directory("tests/files/shortened/").unwrap();
+        let table = collection.generate_ratio_table().unwrap();
+
+        assert_ratio_below_threshold(&table, "balances", "set_balance", 10.0);
+    }
+}
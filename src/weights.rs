@@ -0,0 +1,312 @@
+//! Generation of Substrate `weights.rs` modules from fitted benchmark results.
+//!
+//! The generated module implements the pallet's `WeightInfo` trait for a
+//! `SubstrateWeight<T>` struct, with one method per extrinsic. The `WeightInfo`
+//! trait itself is expected to be defined by the pallet.
+
+use super::tables::StepIncrTableEntry;
+use super::RoundBy;
+
+use std::fmt::Write;
+
+/// A linear model of the extrinsic time, as a function of the benchmark
+/// components (input variables).
+///
+/// All values are in nanoseconds, the same unit as the Substrate benchmark
+/// output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearFit {
+    /// The extrinsic time if all components are zero.
+    pub base: f64,
+    /// The component name and its slope (time per unit of the component).
+    pub slopes: Vec<(String, f64)>,
+}
+
+impl LinearFit {
+    /// Evaluates the model at the given components. Components without a slope
+    /// are ignored, slopes without a component count as zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use libreview::weights::LinearFit;
+    /// let fit = LinearFit {
+    ///     base: 1_000.0,
+    ///     slopes: vec![("u".to_string(), 2.0), ("e".to_string(), 0.5)],
+    /// };
+    ///
+    /// assert_eq!(fit.predict(&[("u", 10), ("e", 100)]), 1_070.0);
+    /// assert_eq!(fit.predict(&[("u", 10)]), 1_020.0);
+    /// ```
+    pub fn predict(&self, components: &[(&str, u64)]) -> f64 {
+        self.slopes
+            .iter()
+            .map(|(name, slope)| {
+                components
+                    .iter()
+                    .find(|(component, _)| component == name)
+                    .map_or(0.0, |(_, value)| slope * *value as f64)
+            })
+            .fold(self.base, |total, time| total + time)
+    }
+    /// Compares the prediction of the model with the measured extrinsic time of
+    /// each step of `entry`, allowing a deviation of `tolerance_pct` percent.
+    pub fn test_against_steps<'a>(
+        &self,
+        entry: &StepIncrTableEntry<'a>,
+        tolerance_pct: f64,
+    ) -> Vec<WeightTestResult<'a>> {
+        let mut results: Vec<WeightTestResult<'a>> = entry
+            .step_incrs
+            .iter()
+            .map(|step| {
+                let components: Vec<(&str, u64)> = entry
+                    .input_var_names
+                    .iter()
+                    .map(|name| name.as_str())
+                    .zip(step.input_vars.iter().copied())
+                    .collect();
+
+                let predicted_ns = self.predict(&components);
+                let measured_ns = step.avg_extrinsic_time.to_ns();
+
+                WeightTestResult {
+                    input_vars: step.input_vars,
+                    predicted_ns,
+                    measured_ns,
+                    within_tolerance: deviation_pct(predicted_ns, measured_ns).abs()
+                        <= tolerance_pct,
+                }
+            })
+            .collect();
+
+        results.sort_by(|a, b| a.input_vars.cmp(b.input_vars));
+        results
+    }
+}
+
+/// The comparison of a model with a single step, see
+/// `LinearFit::test_against_steps`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightTestResult<'a> {
+    pub input_vars: &'a [u64],
+    pub predicted_ns: f64,
+    pub measured_ns: f64,
+    pub within_tolerance: bool,
+}
+
+/// Deviation of the prediction from the measurement, in percent of the
+/// measurement.
+fn deviation_pct(predicted_ns: f64, measured_ns: f64) -> f64 {
+    (predicted_ns - measured_ns) / measured_ns * 100.0
+}
+
+/// Prints the results of `LinearFit::test_against_steps` to stdout, one row
+/// per step.
+pub fn print_test_results(results: &[WeightTestResult]) {
+    build_test_results_table(results).printstd();
+}
+
+fn build_test_results_table(results: &[WeightTestResult]) -> prettytable::Table {
+    let mut table = prettytable::Table::new();
+
+    // Header
+    table.add_row(row![
+        bc =>
+        "Input Vars",
+        "Predicted\nTime (ns)",
+        "Measured\nTime (ns)",
+        "Deviation (%)",
+        "Result"
+    ]);
+
+    // Body
+    for result in results {
+        let deviation = deviation_pct(result.predicted_ns, result.measured_ns);
+        table.add_row(row![
+            format!("{:?}", result.input_vars),
+            result.predicted_ns.round_by(4),
+            result.measured_ns,
+            deviation.round_by(4),
+            if result.within_tolerance {
+                "PASS"
+            } else {
+                "FAIL"
+            },
+        ]);
+    }
+
+    table
+}
+
+/// Converts nanoseconds into the picoseconds of `Weight::ref_time`. Weights
+/// are unsigned, so negative values are clamped at zero.
+fn to_ref_time(ns: f64) -> u64 {
+    if ns.is_finite() {
+        (ns * 1_000.0).round().max(0.0) as u64
+    } else {
+        0
+    }
+}
+
+/// Formats the integer with `_` as thousands separator, like the Substrate
+/// benchmark CLI does.
+fn separated(value: u64) -> String {
+    let digits = value.to_string();
+    let mut s = String::new();
+
+    for (i, c) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i) % 3 == 0 {
+            s.push('_');
+        }
+        s.push(c);
+    }
+
+    s
+}
+
+/// Generates the source code of a `weights.rs` module for `pallet`, using the
+/// fitted model of each extrinsic.
+///
+/// # Example
+/// ```
+/// # use libreview::weights::{generate_weights_module, LinearFit};
+/// let fit = LinearFit {
+///     base: 187_680.2,
+///     slopes: vec![("u".to_string(), 1.5)],
+/// };
+///
+/// let module = generate_weights_module(&[("transfer", fit)], "balances");
+/// assert!(module.contains("fn transfer(u: u32, ) -> Weight {"));
+/// assert!(module.contains("Weight::from_parts(187_680_200, 0)"));
+/// assert!(module.contains("Weight::from_parts(1_500, 0).saturating_mul(u.into())"));
+/// ```
+pub fn generate_weights_module(entries: &[(&str, LinearFit)], pallet: &str) -> String {
+    let mut s = String::new();
+
+    // Writing to a `String` cannot fail.
+    let _ = writeln!(s, "//! Weights for pallet_{}", pallet);
+    let _ = writeln!(s, "//!");
+    let _ = writeln!(
+        s,
+        "//! Generated by bench-review from fitted benchmark results."
+    );
+    let _ = writeln!(s);
+    let _ = writeln!(s, "#![cfg_attr(rustfmt, rustfmt_skip)]");
+    let _ = writeln!(s, "#![allow(unused_parens)]");
+    let _ = writeln!(s);
+    let _ = writeln!(s, "use frame_support::weights::Weight;");
+    let _ = writeln!(s, "use core::marker::PhantomData;");
+    let _ = writeln!(s);
+    let _ = writeln!(s, "/// Weight functions for `pallet_{}`.", pallet);
+    let _ = writeln!(s, "pub struct SubstrateWeight<T>(PhantomData<T>);");
+    let _ = writeln!(
+        s,
+        "impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {{"
+    );
+
+    for (extrinsic, fit) in entries {
+        let params = fit
+            .slopes
+            .iter()
+            .fold(String::new(), |mut params, (name, _)| {
+                let _ = write!(params, "{}: u32, ", name);
+                params
+            });
+
+        let _ = writeln!(s, "\tfn {}({}) -> Weight {{", extrinsic, params);
+        let _ = writeln!(
+            s,
+            "\t\tWeight::from_parts({}, 0)",
+            separated(to_ref_time(fit.base))
+        );
+        for (name, slope) in &fit.slopes {
+            let _ = writeln!(
+                s,
+                "\t\t\t.saturating_add(Weight::from_parts({}, 0).saturating_mul({}.into()))",
+                separated(to_ref_time(*slope)),
+                name
+            );
+        }
+        let _ = writeln!(s, "\t}}");
+    }
+
+    let _ = writeln!(s, "}}");
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deviation_pct() {
+        assert_eq!(deviation_pct(110.0, 100.0), 10.0);
+        assert_eq!(deviation_pct(90.0, 100.0), -10.0);
+        assert!(deviation_pct(90.0, 0.0).is_infinite());
+    }
+    #[test]
+    fn test_test_against_steps() {
+        let (collection, _) =
+            crate::ExtrinsicCollection::from_directory("tests/files/steps/").unwrap();
+        let table = collection.generate_step_table().unwrap();
+        let entry = table
+            .entries()
+            .iter()
+            .find(|e| e.extrinsic == "propose")
+            .unwrap();
+
+        let fit = LinearFit {
+            base: 125_000.0,
+            slopes: vec![("p".to_string(), 500.0)],
+        };
+
+        let results = fit.test_against_steps(entry, 5.0);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].input_vars, [1]);
+        assert_eq!(results[0].predicted_ns, 125_500.0);
+        assert!(results.iter().all(|r| r.within_tolerance));
+
+        // One row per step, below the header
+        let table = build_test_results_table(&results);
+        assert_eq!(table.len(), 4);
+        assert_eq!(table[1][0].get_content(), "[1]");
+        assert_eq!(table[1][4].get_content(), "PASS");
+    }
+    #[test]
+    fn test_separated() {
+        assert_eq!(separated(0), "0");
+        assert_eq!(separated(999), "999");
+        assert_eq!(separated(1_000), "1_000");
+        assert_eq!(separated(187_680_200), "187_680_200");
+    }
+    #[test]
+    fn test_generate_weights_module() {
+        let fits = [
+            (
+                "set_balance",
+                LinearFit {
+                    base: 100.0,
+                    slopes: vec![],
+                },
+            ),
+            (
+                "propose",
+                LinearFit {
+                    base: -5.0,
+                    slopes: vec![("p".to_string(), 2.25), ("q".to_string(), f64::NAN)],
+                },
+            ),
+        ];
+
+        let module = generate_weights_module(&fits, "democracy");
+        assert!(module.starts_with("//! Weights for pallet_democracy\n"));
+        assert!(module
+            .contains("\tfn set_balance() -> Weight {\n\t\tWeight::from_parts(100_000, 0)\n\t}\n"));
+        assert!(module.contains(
+            "\tfn propose(p: u32, q: u32, ) -> Weight {\n\t\tWeight::from_parts(0, 0)\n"
+        ));
+        assert!(module.contains("Weight::from_parts(2_250, 0).saturating_mul(p.into())"));
+        assert!(module.contains("Weight::from_parts(0, 0).saturating_mul(q.into())"));
+        assert!(module.ends_with("\t}\n}\n"));
+    }
+}
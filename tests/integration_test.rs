@@ -0,0 +1,179 @@
+extern crate libreview;
+
+use libreview::tables::{CsvOptions, LineEnding, PrintOptions, SortOrder, TimeFormatter, TimeUnit};
+use libreview::ExtrinsicCollection;
+
+use failure::Error;
+
+/// Runs every table operation on the collection, making sure none of them
+/// panics on the given fixtures.
+fn run_all_table_operations(collection: &ExtrinsicCollection) -> Result<(), Error> {
+    let options = PrintOptions {
+        time_formatter: TimeFormatter {
+            display_unit: TimeUnit::Auto,
+            decimal_places: 2,
+        },
+        group_by_pallet: true,
+    };
+    let csv_options = CsvOptions {
+        delimiter: ';',
+        always_quote: true,
+        line_ending: LineEnding::Windows,
+        ..Default::default()
+    };
+
+    let mut table = collection.generate_ratio_table()?;
+    table.sort_by_ratio_desc();
+    table.sort_by_avg_extrinsic_time_with_order(SortOrder::Descending);
+    table.sort_by_storage_root_time();
+    table.sort_by_pallet_then_ratio();
+    let _ = table.summary();
+    let _ = table.find_duplicates();
+    table.dedup_keep_max_ratio();
+    let _ = table.best_and_worst_per_pallet();
+    let _ = table.to_owned_entries();
+    table.print();
+    table.print_with_options(&options);
+    table.print_extremes();
+    table.write_csv_with_options(Vec::new(), &csv_options)?;
+    table.normalize_by_cpu_frequency(3.0, 2.5)?;
+    table.apply_overhead_discount(1_000.0, 1_000.0);
+
+    let mut step_table = collection.generate_step_table()?;
+    step_table.sort_by_extrinsic_incr_percentage();
+    step_table.sort_entries_by_extrinsic_name();
+    step_table.sort_entries_by_max_extrinsic_percentage();
+    let _ = step_table.summarize_by_pallet();
+    let _ = step_table.low_sample_warnings(5, 5);
+    let _ = step_table.flat_extrinsics(5.0);
+    let _ = step_table.to_worst_case_ratio_table();
+    let _ = step_table.into_ratio_table_at_components(&[10]);
+    step_table.print();
+    step_table.print_with_options(&options);
+    step_table.write_csv_with_options(Vec::new(), &csv_options)?;
+    step_table.apply_overhead_discount(1_000.0, 1_000.0);
+    step_table.remove_flat_extrinsics(5.0);
+
+    Ok(())
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_single_pallet() -> Result<(), Error> {
+    let (collection, failed) = ExtrinsicCollection::from_directory("tests/files/single_pallet/")?;
+    assert!(failed.is_empty());
+
+    let mut table = collection.generate_ratio_table()?;
+    table.sort_by_ratio();
+
+    let expected = [
+        ("balances", "set_balance", 120000.0, 62000.0, 1.0, 0.0),
+        ("balances", "transfer_keep_alive", 122000.0, 71000.0, 1.0167, 1.6667),
+        ("balances", "transfer", 191000.0, 84200.0, 1.5917, 59.1667),
+    ];
+
+    let list = table.raw_list();
+    assert_eq!(list.len(), 3);
+
+    for (counter, entry) in list.into_iter().enumerate() {
+        assert_eq!(entry, expected[counter]);
+    }
+
+    let mut step_table = collection.generate_step_table()?;
+    step_table.sort_entries_by_extrinsic_name();
+    step_table.sort_by_extrinsic_incr_percentage();
+
+    let expected = [
+        ("balances", "set_balance", vec![100], 150000.0, 64000.0, 1.5, 50.0, 6.6667),
+        ("balances", "set_balance", vec![50], 110000.0, 62000.0, 1.1, 10.0, 3.3333),
+        ("balances", "set_balance", vec![1], 100000.0, 60000.0, 1.0, 0.0, 0.0),
+        ("balances", "transfer", vec![100], 201000.0, 88200.0, 1.1105, 11.0497, 9.9751),
+        ("balances", "transfer", vec![50], 191000.0, 84200.0, 1.0552, 5.5249, 4.9875),
+        ("balances", "transfer", vec![1], 181000.0, 80200.0, 1.0, 0.0, 0.0),
+        ("balances", "transfer_keep_alive", vec![100], 123000.0, 72000.0, 1.0165, 1.6529, 2.8571),
+        ("balances", "transfer_keep_alive", vec![50], 122000.0, 71000.0, 1.0083, 0.8264, 1.4286),
+        ("balances", "transfer_keep_alive", vec![1], 121000.0, 70000.0, 1.0, 0.0, 0.0),
+    ];
+
+    let list = step_table.raw_list();
+    assert_eq!(list.len(), 9);
+
+    for (counter, entry) in list.into_iter().enumerate() {
+        assert_eq!(entry.0, expected[counter].0);
+        assert_eq!(entry.1, expected[counter].1);
+        assert_eq!(entry.2, expected[counter].2.as_slice());
+        assert_eq!(entry.3, expected[counter].3);
+        assert_eq!(entry.4, expected[counter].4);
+        assert_eq!(entry.5, expected[counter].5);
+        assert_eq!(entry.6, expected[counter].6);
+        assert_eq!(entry.7, expected[counter].7);
+    }
+
+    run_all_table_operations(&collection)
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_many_pallets() -> Result<(), Error> {
+    let (collection, failed) = ExtrinsicCollection::from_directory("tests/files/many_pallets/")?;
+    assert!(failed.is_empty());
+
+    let mut table = collection.generate_ratio_table()?;
+    table.sort_by_ratio();
+
+    let list = table.raw_list();
+    assert_eq!(list.len(), 17);
+    assert_eq!(list[0], ("assets", "call", 100555.0, 50055.0, 1.0, 0.0));
+    assert_eq!(list[8], ("multisig", "call", 300555.0, 150055.0, 2.989, 198.8961));
+    assert_eq!(list[16], ("vesting", "call", 500555.0, 250055.0, 4.9779, 397.7923));
+
+    let step_table = collection.generate_step_table()?;
+    let summaries = step_table.summarize_by_pallet();
+    assert_eq!(summaries.len(), 17);
+    assert!(summaries.values().all(|s| s.extrinsic_count == 1));
+
+    // Every extrinsic grows by less than one percent between its steps
+    assert_eq!(step_table.flat_extrinsics(1.0).len(), 17);
+
+    run_all_table_operations(&collection)
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_edge_cases() -> Result<(), Error> {
+    let long_pallet = "very_long_pallet_name_for_testing_the_layout_of_the_tables";
+    let long_extrinsic = "do_something_with_an_equally_long_extrinsic_name";
+
+    let (collection, failed) = ExtrinsicCollection::from_directory("tests/files/edge_cases/")?;
+    assert!(failed.is_empty());
+
+    let mut table = collection.generate_ratio_table()?;
+    table.sort_by_ratio();
+
+    let list = table.raw_list();
+    assert_eq!(list.len(), 3);
+    // Single step, single repeat
+    assert_eq!(list[0], ("timestamp", "set", 5000.0, 1000.0, 1.0, 0.0));
+    // Zero storage root time
+    assert_eq!(list[1], ("nicks", "set_name", 156000.0, 0.0, 31.2, 3020.0));
+    assert_eq!(list[2], (long_pallet, long_extrinsic, 451500.0, 135000.0, 90.3, 8930.0));
+
+    let mut step_table = collection.generate_step_table()?;
+    step_table.sort_by_extrinsic_incr_percentage();
+
+    let list = step_table.raw_list();
+    assert_eq!(list.len(), 5);
+
+    // The storage root increase of zero storage root times is undefined
+    let nicks: Vec<_> = list.iter().filter(|e| e.0 == "nicks").collect();
+    assert_eq!(nicks[0].6, 6.6225);
+    assert!(nicks.iter().all(|e| e.7.is_nan()));
+
+    let timestamp: Vec<_> = list.iter().filter(|e| e.0 == "timestamp").collect();
+    assert_eq!(*timestamp[0], ("timestamp", "set", [1].as_ref(), 5000.0, 1000.0, 1.0, 0.0, 0.0));
+
+    let warnings = step_table.low_sample_warnings(5, 5);
+    assert_eq!(warnings.len(), 3);
+
+    run_all_table_operations(&collection)
+}
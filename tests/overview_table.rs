@@ -1,6 +1,10 @@
+// Some tests index the expected values with a manual counter.
+#![allow(clippy::explicit_counter_loop)]
+
 extern crate libreview;
 
-use libreview::{ExtrinsicCollection, FileScraper};
+use libreview::tables::HardwareProfile;
+use libreview::{ExtrinsicCollection, FileScraper, MergeStrategy};
 
 use failure::Error;
 
@@ -92,3 +96,302 @@ fn test_overview_table_shortened() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_overview_table_sort_desc() -> Result<(), Error> {
+    let scraper = FileScraper::new("tests/files/shortened/")?;
+    let mut collection = ExtrinsicCollection::new();
+
+    for result in scraper {
+        let extrinsic_result = result?.parse()?;
+        collection.push(extrinsic_result);
+    }
+
+    let mut table = collection.generate_ratio_table().unwrap();
+    table.sort_by_ratio_desc();
+
+    let expected = [
+        ("democracy", "delegate"),
+        ("staking", "bond_extra"),
+        ("balances", "transfer"),
+        ("treasury", "tip_new"),
+        ("identity", "add_registrar"),
+    ];
+
+    let list = table.raw_list();
+    assert_eq!(list.len(), 5);
+
+    for (counter, entry) in list.into_iter().enumerate() {
+        assert_eq!(entry.0, expected[counter].0);
+        assert_eq!(entry.1, expected[counter].1);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_overview_table_normalize_by_cpu_frequency() -> Result<(), Error> {
+    let scraper = FileScraper::new("tests/files/shortened/")?;
+    let mut collection = ExtrinsicCollection::new();
+
+    for result in scraper {
+        let extrinsic_result = result?.parse()?;
+        collection.push(extrinsic_result);
+    }
+
+    let mut table = collection.generate_ratio_table().unwrap();
+    table.sort_by_ratio();
+    table.normalize_by_cpu_frequency(2.0, 4.0)?;
+
+    let expected = [
+        ("identity", "add_registrar", 38300.4, 21937.2, 1.0, 0.0),
+        ("treasury", "tip_new", 70329.9167, 30804.1667, 1.8363, 83.6271),
+    ];
+
+    let list = table.raw_list();
+    for (counter, entry) in list.into_iter().take(2).enumerate() {
+        assert_eq!(entry.0, expected[counter].0);
+        assert_eq!(entry.1, expected[counter].1);
+        assert_eq!(entry.2, expected[counter].2);
+        assert_eq!(entry.3, expected[counter].3);
+        assert_eq!(entry.4, expected[counter].4);
+        assert_eq!(entry.5, expected[counter].5);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_overview_table_normalize_by_hardware_profile() -> Result<(), Error> {
+    let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/")?;
+    let mut table = collection.generate_ratio_table()?;
+    table.sort_by_ratio();
+
+    let measured = HardwareProfile {
+        cpu_ghz: 2.0,
+        memory_bandwidth_gbps: 10.0,
+    };
+    let reference = HardwareProfile {
+        cpu_ghz: 4.0,
+        memory_bandwidth_gbps: 40.0,
+    };
+    table.normalize_by_hardware_profile(&measured, &reference)?;
+
+    let list = table.raw_list();
+    assert_eq!(
+        list[0],
+        ("identity", "add_registrar", 38300.4, 10968.6, 1.0, 0.0)
+    );
+    assert_eq!(
+        list[1],
+        ("treasury", "tip_new", 70329.9167, 15402.0833, 1.8363, 83.6271)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overview_table_normalize_by_invalid_hardware_profile() -> Result<(), Error> {
+    let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/")?;
+    let mut table = collection.generate_ratio_table()?;
+    let untouched = collection.generate_ratio_table()?;
+
+    let measured = HardwareProfile {
+        cpu_ghz: 2.0,
+        memory_bandwidth_gbps: 10.0,
+    };
+    for reference in &[
+        HardwareProfile {
+            cpu_ghz: 0.0,
+            ..measured.clone()
+        },
+        HardwareProfile {
+            cpu_ghz: f64::NAN,
+            ..measured.clone()
+        },
+        HardwareProfile {
+            memory_bandwidth_gbps: 0.0,
+            ..measured.clone()
+        },
+    ] {
+        assert!(table
+            .normalize_by_hardware_profile(&measured, reference)
+            .is_err());
+    }
+    assert!(table
+        .normalize_by_cpu_frequency(f64::INFINITY, 4.0)
+        .is_err());
+
+    assert_eq!(table.raw_list(), untouched.raw_list());
+    Ok(())
+}
+
+#[test]
+fn test_overview_table_from_directory() -> Result<(), Error> {
+    let (collection, failed) = ExtrinsicCollection::from_directory("tests/files/shortened/")?;
+    assert!(failed.is_empty());
+
+    let table = collection.generate_ratio_table().unwrap();
+    assert_eq!(table.raw_list().len(), 5);
+
+    // Subdirectories are skipped and the test sources are not valid
+    // benchmark results.
+    let (collection, failed) = ExtrinsicCollection::from_directory("tests/")?;
+    assert!(collection.generate_ratio_table().is_err());
+    assert!(failed
+        .iter()
+        .any(|(path, _)| path.ends_with("overview_table.rs")));
+
+    Ok(())
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_overview_table_apply_overhead_discount() -> Result<(), Error> {
+    let scraper = FileScraper::new("tests/files/shortened/")?;
+    let mut collection = ExtrinsicCollection::new();
+
+    for result in scraper {
+        let extrinsic_result = result?.parse()?;
+        collection.push(extrinsic_result);
+    }
+
+    let mut table = collection.generate_ratio_table().unwrap();
+    table.sort_by_ratio();
+    table.apply_overhead_discount(1000.0, 500.0);
+
+    let expected = [
+        ("identity", "add_registrar", 75600.8, 43374.4, 1.0, 0.0),
+        ("treasury", "tip_new", 139659.8333, 61108.3333, 1.8473, 84.7333),
+    ];
+
+    let list = table.raw_list();
+    for (counter, entry) in list.into_iter().take(2).enumerate() {
+        assert_eq!(entry.0, expected[counter].0);
+        assert_eq!(entry.1, expected[counter].1);
+        assert_eq!(entry.2, expected[counter].2);
+        assert_eq!(entry.3, expected[counter].3);
+        assert_eq!(entry.4, expected[counter].4);
+        assert_eq!(entry.5, expected[counter].5);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_overview_table_apply_overhead_discount_exceeding_fastest() -> Result<(), Error> {
+    let (collection, _) = ExtrinsicCollection::from_directory("tests/files/shortened/")?;
+
+    let mut table = collection.generate_ratio_table()?;
+    // Larger than the time of `identity::add_registrar`, the fastest extrinsic.
+    table.apply_overhead_discount(100_000.0, 0.0);
+    table.sort_by_ratio();
+
+    let list = table.raw_list();
+    assert_eq!(list.len(), 5);
+    assert_eq!(
+        list[0],
+        ("identity", "add_registrar", 0.0, 43874.4, 0.0, -100.0)
+    );
+    assert_eq!(list[1].1, "tip_new");
+    assert_eq!(list[1].4, 1.0);
+    assert!(list.iter().all(|e| e.4.is_finite() && e.5.is_finite()));
+
+    // Without any positive time left, there is nothing to base the ratios on.
+    table.apply_overhead_discount(10_000_000.0, 0.0);
+    let list = table.raw_list();
+    assert_eq!(list.len(), 5);
+    assert!(list
+        .iter()
+        .all(|e| e.2 == 0.0 && e.4.is_nan() && e.5.is_nan()));
+
+    Ok(())
+}
+
+#[test]
+fn test_overview_table_skips_results_without_measurements() -> Result<(), Error> {
+    let (collection, failed) = ExtrinsicCollection::from_directory("tests/files/no_measurements/")?;
+    assert!(failed.is_empty());
+
+    let table = collection.generate_ratio_table()?;
+    assert_eq!(
+        table.raw_list(),
+        vec![("timestamp", "set", 5000.0, 1000.0, 1.0, 0.0)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overview_table_merge_and_filter() -> Result<(), Error> {
+    let (full, _) = ExtrinsicCollection::from_directory("tests/files/full/")?;
+    let (steps, _) = ExtrinsicCollection::from_directory("tests/files/steps/")?;
+
+    let collection = full.merge(steps);
+    assert_eq!(collection.generate_ratio_table()?.raw_list().len(), 7);
+
+    let collection = collection.filter_by_pallet("balances");
+    let mut table = collection.generate_ratio_table()?;
+    table.sort_by_ratio();
+
+    let list = table.raw_list();
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0].1, "set_balance_killing");
+    assert_eq!(list[1].1, "transfer");
+
+    assert!(collection
+        .filter_by_pallet("identity")
+        .generate_ratio_table()
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_overview_table_merge_with_strategy() -> Result<(), Error> {
+    let load = |path| ExtrinsicCollection::from_directory(path).map(|(c, _)| c);
+    let transfer_time = |collection: &ExtrinsicCollection| -> Result<f64, Error> {
+        let table = collection.generate_ratio_table()?;
+        Ok(table
+            .get("balances", "transfer")
+            .unwrap()
+            .avg_extrinsic_time
+            .to_ns())
+    };
+
+    let full = transfer_time(&load("tests/files/full/")?)?;
+    let shortened = transfer_time(&load("tests/files/shortened/")?)?;
+    assert!(full != shortened);
+
+    let strategies = [
+        (MergeStrategy::KeepFirst, full),
+        (MergeStrategy::KeepSecond, shortened),
+        (MergeStrategy::KeepHigherRatio, full.max(shortened)),
+        (MergeStrategy::KeepLowerRatio, full.min(shortened)),
+    ];
+
+    for (strategy, expected) in strategies.iter() {
+        let collection = load("tests/files/full/")?
+            .merge_with_strategy(load("tests/files/shortened/")?, *strategy)?;
+        assert_eq!(collection.generate_ratio_table()?.raw_list().len(), 5);
+        assert_eq!(transfer_time(&collection)?, *expected);
+    }
+
+    // Both results have the same amount of measurements
+    let collection = load("tests/files/full/")?
+        .merge_with_strategy(load("tests/files/full/")?, MergeStrategy::Average)?;
+    assert_eq!(transfer_time(&collection)?, full);
+
+    // Results with different input variables cannot be averaged
+    let collection = load("tests/files/full/")?
+        .merge_with_strategy(load("tests/files/single_pallet/")?, MergeStrategy::Average);
+    assert!(collection.is_err());
+
+    // Results with a different amount of measurements cannot be averaged
+    let collection = load("tests/files/full/")?
+        .merge_with_strategy(load("tests/files/shortened/")?, MergeStrategy::Average);
+    assert!(collection.is_err());
+
+    Ok(())
+}
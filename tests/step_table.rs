@@ -1,5 +1,9 @@
+// Some tests index the expected values with a manual counter.
+#![allow(clippy::explicit_counter_loop)]
+
 extern crate libreview;
 
+use libreview::tables::{StepIncr, StepIncrTable, StepIncrTableEntry};
 use libreview::{ExtrinsicCollection, FileScraper};
 
 use failure::Error;
@@ -51,3 +55,235 @@ fn test_step_table() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_step_table_low_sample_warnings() -> Result<(), Error> {
+    let scraper = FileScraper::new("tests/files/steps/")?;
+    let mut collection = ExtrinsicCollection::new();
+
+    for result in scraper {
+        let extrinsic_result = result?.parse()?;
+        collection.push(extrinsic_result);
+    }
+
+    let table = collection.generate_step_table().unwrap();
+
+    // All files were benchmarked with 10 steps and 10 repeats.
+    assert!(table.low_sample_warnings(10, 10).is_empty());
+
+    let mut warnings = table.low_sample_warnings(10, 11);
+    warnings.sort_by(|a, b| a.pallet.cmp(b.pallet));
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].pallet, "balances");
+    assert_eq!(warnings[0].extrinsic, "set_balance_killing");
+    assert_eq!(warnings[0].actual_steps, 10);
+    assert_eq!(warnings[0].actual_repeats, 10);
+    assert_eq!(warnings[1].pallet, "democracy");
+
+    Ok(())
+}
+
+#[test]
+fn test_step_table_summarize_by_pallet() -> Result<(), Error> {
+    let scraper = FileScraper::new("tests/files/steps/")?;
+    let mut collection = ExtrinsicCollection::new();
+
+    for result in scraper {
+        let extrinsic_result = result?.parse()?;
+        collection.push(extrinsic_result);
+    }
+
+    let table = collection.generate_step_table().unwrap();
+    let summary = table.summarize_by_pallet();
+    assert_eq!(summary.len(), 2);
+
+    let balances = &summary["balances"];
+    assert_eq!(balances.extrinsic_count, 1);
+    assert_eq!(balances.total_steps, 3);
+    assert_eq!(balances.avg_max_extrinsic_percentage, 15.6470);
+    assert_eq!(balances.avg_max_storage_root_percentage, 27.0004);
+    assert_eq!(balances.most_variable_extrinsic, "set_balance_killing");
+
+    let democracy = &summary["democracy"];
+    assert_eq!(democracy.extrinsic_count, 1);
+    assert_eq!(democracy.total_steps, 3);
+    assert_eq!(democracy.avg_max_extrinsic_percentage, 7.9605);
+    assert_eq!(democracy.avg_max_storage_root_percentage, 27.1982);
+    assert_eq!(democracy.most_variable_extrinsic, "propose");
+
+    Ok(())
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_step_table_to_worst_case_ratio_table() -> Result<(), Error> {
+    let scraper = FileScraper::new("tests/files/steps/")?;
+    let mut collection = ExtrinsicCollection::new();
+
+    for result in scraper {
+        let extrinsic_result = result?.parse()?;
+        collection.push(extrinsic_result);
+    }
+
+    let step_table = collection.generate_step_table().unwrap();
+    let mut table = step_table.to_worst_case_ratio_table();
+    table.sort_by_ratio();
+
+    let expected = [
+        ("balances", "set_balance_killing", 122488.6667, 79915.0, 1.0, 0.0),
+        ("democracy", "propose", 135927.0, 106922.0, 1.1097, 10.9711),
+    ];
+
+    let list = table.raw_list();
+    assert_eq!(list.len(), 2);
+
+    for (counter, entry) in list.into_iter().enumerate() {
+        assert_eq!(entry.0, expected[counter].0);
+        assert_eq!(entry.1, expected[counter].1);
+        assert_eq!(entry.2, expected[counter].2);
+        assert_eq!(entry.3, expected[counter].3);
+        assert_eq!(entry.4, expected[counter].4);
+        assert_eq!(entry.5, expected[counter].5);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_step_table_write_per_extrinsic_csv() -> Result<(), Error> {
+    let scraper = FileScraper::new("tests/files/steps/")?;
+    let mut collection = ExtrinsicCollection::new();
+
+    for result in scraper {
+        let extrinsic_result = result?.parse()?;
+        collection.push(extrinsic_result);
+    }
+
+    let output_dir = std::env::temp_dir().join("bench-review-per-extrinsic-csv/nested");
+    let table = collection.generate_step_table().unwrap();
+    let mut paths = table.write_per_extrinsic_csv(&output_dir)?;
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        vec![
+            output_dir.join("balances_set_balance_killing.csv"),
+            output_dir.join("democracy_propose.csv"),
+        ]
+    );
+
+    let content = std::fs::read_to_string(&paths[0])?;
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(
+        lines[0],
+        "u,e,avg_extrinsic_time,avg_storage_root_time,ratio,extrinsic_incr_percentage,storage_root_incr_percentage"
+    );
+    assert_eq!(lines[1], "1,1000,122488.6667,79915,1.1565,15.647,27.0004");
+    assert_eq!(lines[3], "496,1000,105916,62925,1,0,0");
+
+    std::fs::remove_dir_all(&output_dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_step_table_apply_overhead_discount_exceeding_fastest() -> Result<(), Error> {
+    let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/")?;
+
+    let mut table = collection.generate_step_table()?;
+    // Larger than the fastest step of `balances::set_balance_killing`.
+    table.apply_overhead_discount(106_000.0, 0.0);
+
+    let list = table.raw_list();
+    assert_eq!(list.len(), 6);
+    let clamped = list.iter().find(|e| e.2 == [496, 1000]).unwrap();
+    assert_eq!((clamped.3, clamped.5, clamped.6), (0.0, 0.0, -100.0));
+    assert!(list.iter().all(|e| e.5.is_finite() && e.6.is_finite()));
+    assert!(list.iter().any(|e| e.0 == "balances" && e.5 == 1.0));
+
+    Ok(())
+}
+
+#[test]
+fn test_step_table_skips_inconsistent_extrinsics() -> Result<(), Error> {
+    // Contains a stale result of `democracy::propose` with a different set of components.
+    let (collection, failed) =
+        ExtrinsicCollection::from_directory("tests/files/mismatched_components/")?;
+    assert!(failed.is_empty());
+
+    let table = collection.generate_step_table()?;
+    assert_eq!(
+        table.extrinsic_names(),
+        vec![("balances", "set_balance_killing")]
+    );
+    assert_eq!(table.raw_list().len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_step_table_write_per_extrinsic_csv_colliding_names() -> Result<(), Error> {
+    let input_vars = vec![1];
+    let entry = |pallet, extrinsic| StepIncrTableEntry {
+        pallet,
+        extrinsic,
+        step_incrs: vec![StepIncr {
+            input_vars: &input_vars,
+            avg_extrinsic_time: 100.0.into(),
+            avg_storage_root_time: 100.0.into(),
+            ratio: 1.0,
+            extrinsic_incr_percentage: 0.0,
+            storage_root_incr_percentage: 0.0,
+        }],
+        ..Default::default()
+    };
+    let table = StepIncrTable::from_entries(vec![
+        entry("a_b", "c"),
+        entry("a", "b_c"),
+        entry("foo", "bar.baz"),
+        entry("foo", "bar_baz"),
+        entry("a", "b_c_2"),
+    ])?;
+
+    let output_dir = std::env::temp_dir().join("bench-review-per-extrinsic-csv-colliding");
+    let paths = table.write_per_extrinsic_csv(&output_dir)?;
+
+    assert_eq!(
+        paths,
+        vec![
+            output_dir.join("a_b_c.csv"),
+            output_dir.join("a_b_c_2.csv"),
+            output_dir.join("foo_bar_baz.csv"),
+            output_dir.join("foo_bar_baz_2.csv"),
+            output_dir.join("a_b_c_2_2.csv"),
+        ]
+    );
+    assert!(paths.iter().all(|path| path.exists()));
+
+    std::fs::remove_dir_all(&output_dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_step_table_total_incr_percentage() -> Result<(), Error> {
+    let (collection, _) = ExtrinsicCollection::from_directory("tests/files/steps/")?;
+    let table = collection.generate_step_table()?;
+    let entry = table
+        .entries()
+        .iter()
+        .find(|e| e.extrinsic == "set_balance_killing")
+        .unwrap();
+
+    let baseline = entry.baseline_step().unwrap();
+    assert_eq!(baseline.input_vars, &vec![496, 1000]);
+    assert_eq!(entry.total_incr_percentage(baseline), 0.0);
+
+    let smallest = entry
+        .step_incrs
+        .iter()
+        .find(|s| s.input_vars == &vec![1, 1000])
+        .unwrap();
+    assert_eq!(entry.total_incr_percentage(smallest), 19.8783);
+
+    Ok(())
+}